@@ -1,35 +1,353 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+use syn::{parse_macro_input, punctuated::Punctuated, spanned::Spanned, DeriveInput};
 
 #[proc_macro_derive(Transmogrify, attributes(transmogrify))]
 pub fn transmogrify_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
-    match do_transmogrify_derive(input) {
+    match do_transmogrify_derive(input, Flavor::Runtime) {
         Ok(output) => output.into(),
         Err(err) => err.into_compile_error().into(),
     }
 }
 
-struct TransmogrifyAttr {
-    path: syn::Path,
+#[proc_macro_derive(TransmogrifyConst, attributes(transmogrify))]
+pub fn transmogrify_const_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match do_transmogrify_derive(input, Flavor::Const) {
+        Ok(output) => output.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// The trait being derived. The two share attributes and code generation;
+/// they differ in the trait and method names and in that `TransmogrifyConst`
+/// is fallible.
+#[derive(Clone, Copy, PartialEq)]
+enum Flavor {
+    /// `Transmogrify`
+    Runtime,
+    /// `TransmogrifyConst`
+    Const,
+}
+
+impl Flavor {
+    fn trait_path(self) -> TokenStream {
+        match self {
+            Flavor::Runtime => quote! { ::transmogrify::Transmogrify },
+            Flavor::Const => quote! { ::transmogrify::TransmogrifyConst },
+        }
+    }
+
+    /// Emit the contained `value`, propagating any error.
+    fn call(self, value: TokenStream) -> TokenStream {
+        let trait_path = self.trait_path();
+        match self {
+            Flavor::Runtime => quote! { __cx.emit(#value) },
+            Flavor::Const => quote! { #trait_path::transmogrify_const_with(#value, __cx)? },
+        }
+    }
+
+    /// The trait method, given its body.
+    fn method(self, body: TokenStream) -> TokenStream {
+        match self {
+            Flavor::Runtime => quote! {
+                fn transmogrify_with(
+                    &self,
+                    __cx: &::transmogrify::Context,
+                ) -> proc_macro2::TokenStream {
+                    #body
+                }
+            },
+            Flavor::Const => quote! {
+                fn transmogrify_const_with(
+                    &self,
+                    __cx: &::transmogrify::Context,
+                ) -> ::core::result::Result<
+                    proc_macro2::TokenStream,
+                    ::transmogrify::ConstError,
+                > {
+                    ::core::result::Result::Ok({
+                        #body
+                    })
+                }
+            },
+        }
+    }
+}
+
+/// Attributes that may appear on the type, e.g.
+/// `#[transmogrify(prefix = <path>)]`.
+enum TransmogrifyAttr {
+    /// The consumer-visible path prefix of the type.
+    Prefix(Prefix),
+    /// Where clause predicates for the generated impl that replace those
+    /// inferred from the type's generic parameters.
+    Bound(Vec<syn::WherePredicate>),
+    /// A public function (relative to the prefix) to call with the struct's
+    /// fields in place of a struct literal.
+    Constructor(syn::Path),
+    /// A type with a public representation that the type can be converted
+    /// to and from; the generated code emits the representation and converts
+    /// it using `From`.
+    From(syn::Type),
+    /// The consumer-visible name of the type if it differs from the name of
+    /// the type e.g. if it is re-exported with `pub use Name as OtherName`.
+    Rename(syn::Ident),
+    /// A serialization format in which to embed the value in place of a
+    /// structural expression; see `transmogrify::via_serde`.
+    Via(syn::LitStr),
 }
 
 impl syn::parse::Parse for TransmogrifyAttr {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let prefix = input.parse::<syn::Ident>()?;
-        if prefix != "prefix" {
-            return Err(syn::Error::new(prefix.span(), "expected `prefix`"));
+        let ident = input.parse::<syn::Ident>()?;
+        if ident == "local" {
+            return Ok(Self::Prefix(Prefix::Local));
         }
         let _ = input.parse::<syn::Token![=]>()?;
-        let path = input.parse()?;
-        Ok(Self { path })
+        if ident == "prefix" {
+            if input.peek(syn::Token![$]) {
+                let _ = input.parse::<syn::Token![$]>()?;
+                let _ = input.parse::<syn::Token![crate]>()?;
+                Ok(Self::Prefix(Prefix::CrateRoot))
+            } else {
+                Ok(Self::Prefix(Prefix::Path(input.parse()?)))
+            }
+        } else if ident == "bound" {
+            let lit = input.parse::<syn::LitStr>()?;
+            let predicates = lit
+                .parse_with(Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated)?;
+            Ok(Self::Bound(predicates.into_iter().collect()))
+        } else if ident == "constructor" {
+            Ok(Self::Constructor(input.parse()?))
+        } else if ident == "from" {
+            Ok(Self::From(input.parse()?))
+        } else if ident == "rename" {
+            Ok(Self::Rename(input.parse()?))
+        } else if ident == "via" {
+            let format = input.parse::<syn::LitStr>()?;
+            if format.value() != "json" {
+                return Err(syn::Error::new(
+                    format.span(),
+                    "unsupported format; expected \"json\"",
+                ));
+            }
+            Ok(Self::Via(format))
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                "expected `prefix`, `local`, `bound`, `constructor`, `from`, `rename`, or `via`",
+            ))
+        }
+    }
+}
+
+/// The source of the consumer-visible path prefix of the type.
+enum Prefix {
+    /// A fixed path e.g. `#[transmogrify(prefix = my_crate::module)]`. If it
+    /// starts with the name of a crate, that crate's path is looked up with
+    /// `transmogrify::Context::crate_path`.
+    Path(syn::Path),
+    /// A path determined by the caller at runtime; see
+    /// `transmogrify::Context::with_crate_root`. Specified as
+    /// `#[transmogrify(prefix = $crate)]`.
+    CrateRoot,
+    /// No prefix; the type is assumed to be in scope wherever the output is
+    /// used. Specified as `#[transmogrify(local)]`.
+    Local,
+}
+
+/// Attributes that may appear on fields and variants, e.g.
+/// `#[transmogrify(skip)]` or `#[transmogrify(default = <expr>)]`.
+enum MemberAttr {
+    /// Don't transmogrify the value; emit `Default::default()` instead.
+    Skip,
+    /// Don't transmogrify the value; emit the given expression instead.
+    Default(Option<syn::Expr>),
+    /// Emit the value by calling the given function rather than
+    /// `Transmogrify::transmogrify_with`. Valid only on fields.
+    With(syn::Path),
+    /// The full, consumer-visible path of a variant, overriding the default
+    /// of `<prefix>::<type>::<variant>`. Valid only on variants.
+    Path(syn::Path),
+}
+
+impl syn::parse::Parse for MemberAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        if ident == "skip" {
+            Ok(Self::Skip)
+        } else if ident == "default" {
+            if input.peek(syn::Token![=]) {
+                let _ = input.parse::<syn::Token![=]>()?;
+                Ok(Self::Default(Some(input.parse()?)))
+            } else {
+                Ok(Self::Default(None))
+            }
+        } else if ident == "with" {
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(Self::With(input.parse()?))
+        } else if ident == "path" {
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(Self::Path(input.parse()?))
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                "expected `skip`, `default`, `with`, or `path`",
+            ))
+        }
+    }
+}
+
+/// The interpretation of the transmogrify attributes on a field or variant.
+#[derive(Default)]
+struct MemberOpts {
+    /// If present, the tokens to emit in place of the transmogrified value.
+    replacement: Option<TokenStream>,
+    /// If present, a function of the form `fn(&T, &Context) -> TokenStream`
+    /// to use in place of `Transmogrify::transmogrify_with`.
+    with: Option<syn::Path>,
+    /// If present, the path to use for a variant.
+    path: Option<syn::Path>,
+    /// The location of a `skip` or `default` without an expression; its
+    /// replacement, `Default::default()`, isn't const.
+    implicit_default: Option<proc_macro2::Span>,
+}
+
+impl MemberOpts {
+    fn from_field_attrs(
+        attrs: &[syn::Attribute],
+        flavor: Flavor,
+        errors: &mut Vec<syn::Error>,
+    ) -> Self {
+        let opts = Self::from_attrs(attrs, errors);
+        if let Some(path) = &opts.path {
+            errors.push(syn::Error::new(
+                path.span(),
+                "`path` may only be applied to variants",
+            ));
+        }
+        if flavor == Flavor::Const {
+            if let Some(with) = &opts.with {
+                errors.push(syn::Error::new(
+                    with.span(),
+                    "`with` is not supported by TransmogrifyConst",
+                ));
+            }
+        }
+        opts.check_const(flavor, errors);
+        opts
+    }
+
+    fn from_variant_attrs(
+        attrs: &[syn::Attribute],
+        flavor: Flavor,
+        errors: &mut Vec<syn::Error>,
+    ) -> Self {
+        let opts = Self::from_attrs(attrs, errors);
+        if let Some(with) = &opts.with {
+            errors.push(syn::Error::new(
+                with.span(),
+                "`with` may only be applied to fields",
+            ));
+        }
+        opts.check_const(flavor, errors);
+        opts
+    }
+
+    /// Report a replacement that can't be evaluated in a const context.
+    fn check_const(&self, flavor: Flavor, errors: &mut Vec<syn::Error>) {
+        if let (Flavor::Const, Some(span)) = (flavor, self.implicit_default) {
+            errors.push(syn::Error::new(
+                span,
+                "TransmogrifyConst requires a const replacement: \
+                `#[transmogrify(default = <expr>)]`",
+            ));
+        }
+    }
+
+    fn from_attrs(attrs: &[syn::Attribute], errors: &mut Vec<syn::Error>) -> Self {
+        let mut opts = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("transmogrify") {
+                continue;
+            }
+            let items = match attr
+                .parse_args_with(Punctuated::<MemberAttr, syn::Token![,]>::parse_terminated)
+            {
+                Ok(items) => items,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            };
+
+            for item in items {
+                match item {
+                    MemberAttr::Skip | MemberAttr::Default(None) => {
+                        if opts.replacement.is_some() {
+                            errors.push(syn::Error::new(
+                                attr.span(),
+                                "only one of `skip` or `default` may be specified",
+                            ));
+                        }
+                        let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+                        opts.replacement =
+                            Some(quote! { #pound __core::default::Default::default() });
+                        opts.implicit_default = Some(attr.span());
+                    }
+                    MemberAttr::Default(Some(expr)) => {
+                        if opts.replacement.is_some() {
+                            errors.push(syn::Error::new(
+                                attr.span(),
+                                "only one of `skip` or `default` may be specified",
+                            ));
+                        }
+                        opts.replacement = Some(expr.to_token_stream());
+                        opts.implicit_default = None;
+                    }
+                    MemberAttr::With(path) => {
+                        if opts.with.is_some() {
+                            errors.push(syn::Error::new(
+                                path.span(),
+                                "`with` may only be specified once",
+                            ));
+                        }
+                        opts.with = Some(path);
+                    }
+                    MemberAttr::Path(path) => {
+                        if opts.path.is_some() {
+                            errors.push(syn::Error::new(
+                                path.span(),
+                                "`path` may only be specified once",
+                            ));
+                        }
+                        opts.path = Some(path);
+                    }
+                }
+                if opts.replacement.is_some() && opts.with.is_some() {
+                    errors.push(syn::Error::new(
+                        attr.span(),
+                        "`with` may not be combined with `skip` or `default`",
+                    ));
+                }
+            }
+        }
+
+        opts
     }
 }
 
-fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
+fn do_transmogrify_derive(input: DeriveInput, flavor: Flavor) -> syn::Result<TokenStream> {
     let mut errors = Vec::new();
-    let mut prefix = TokenStream::new();
+    let mut prefix = None;
+    let mut bound = None;
+    let mut constructor = None;
+    let mut from = None;
+    let mut rename = None;
+    let mut via = None;
     let mut found = false;
 
     for attr @ syn::Attribute { meta, .. } in &input.attrs {
@@ -38,12 +356,38 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
                 if path.segments.len() == 1
                     && path.segments.last().unwrap().ident == "transmogrify" =>
             {
-                found = true;
-                match syn::parse2::<TransmogrifyAttr>(tokens.clone()) {
-                    Ok(TransmogrifyAttr { path, .. }) => {
-                        prefix = path.to_token_stream();
+                let parser = Punctuated::<TransmogrifyAttr, syn::Token![,]>::parse_terminated;
+                match syn::parse::Parser::parse2(parser, tokens.clone()) {
+                    Ok(items) => {
+                        for item in items {
+                            match item {
+                                TransmogrifyAttr::Prefix(p) => {
+                                    found = true;
+                                    prefix = Some(p);
+                                }
+                                TransmogrifyAttr::Bound(predicates) => {
+                                    bound = Some(predicates);
+                                }
+                                TransmogrifyAttr::Constructor(path) => {
+                                    constructor = Some(path);
+                                }
+                                TransmogrifyAttr::From(ty) => {
+                                    from = Some(ty);
+                                }
+                                TransmogrifyAttr::Rename(ident) => {
+                                    rename = Some(ident);
+                                }
+                                TransmogrifyAttr::Via(format) => {
+                                    // No path is emitted so none is required.
+                                    found = true;
+                                    via = Some(format);
+                                }
+                            }
+                        }
                     }
                     Err(e) => {
+                        // Don't additionally complain about a missing prefix.
+                        found = true;
                         errors.push(e);
                     }
                 }
@@ -65,7 +409,8 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
     if !found {
         errors.push(syn::Error::new(
             input.span(),
-            "must specify a path prefix #[transmogrify(prefix = <path>)]",
+            "must specify a path prefix #[transmogrify(prefix = <path>)] \
+            or #[transmogrify(local)]",
         ));
     }
 
@@ -79,7 +424,36 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
             ));
         }
     }
+    if let (Flavor::Const, Some(ty)) = (flavor, &from) {
+        errors.push(syn::Error::new(
+            ty.span(),
+            "`from` is not supported by TransmogrifyConst",
+        ));
+    }
+    if let Some(format) = &via {
+        if flavor == Flavor::Const {
+            errors.push(syn::Error::new(
+                format.span(),
+                "`via` is not supported by TransmogrifyConst",
+            ));
+        }
+        if constructor.is_some() || from.is_some() {
+            errors.push(syn::Error::new(
+                format.span(),
+                "`via` may not be combined with `constructor` or `from`",
+            ));
+        }
+    }
+    if constructor.is_some() && from.is_some() {
+        errors.push(syn::Error::new(
+            input.span(),
+            "only one of `constructor` or `from` may be specified",
+        ));
+    }
     match &input.data {
+        // Fields needn't be pub if the value isn't constructed from them
+        // directly.
+        syn::Data::Struct(_) if constructor.is_some() || from.is_some() || via.is_some() => {}
         syn::Data::Struct(syn::DataStruct { fields, .. }) => {
             for field in fields {
                 match &field.vis {
@@ -90,7 +464,14 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
                 }
             }
         }
-        syn::Data::Enum(_) => {}
+        syn::Data::Enum(_) => {
+            if let Some(path) = &constructor {
+                errors.push(syn::Error::new(
+                    path.span(),
+                    "`constructor` may only be used with structs",
+                ));
+            }
+        }
         syn::Data::Union(_) => {
             return Err(syn::Error::new(
                 input.span(),
@@ -100,28 +481,107 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
     }
 
     let name = &input.ident;
-    let transmogrify = quote! {
-        ::transmogrify::Transmogrify::transmogrify
-    };
+    let public_name = rename.as_ref().unwrap_or(name);
     let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+    let (root, prefix) = match prefix.unwrap_or(Prefix::Local) {
+        Prefix::Path(path) => match extern_crate(&path) {
+            // The crate is looked up in the context so that callers may remap
+            // it, just as they may for crates named by impls in transmogrify.
+            Some(krate) => {
+                let name = krate.to_string();
+                let rest = path.segments.iter().skip(1);
+                (
+                    quote! {
+                        let __crate = __cx.crate_path(#name);
+                    },
+                    quote! { #pound __crate #( :: #rest )* :: },
+                )
+            }
+            None => (TokenStream::new(), quote! { #path:: }),
+        },
+        Prefix::CrateRoot => (
+            quote! {
+                let __crate_root = __cx.crate_root();
+            },
+            quote! { #pound __crate_root:: },
+        ),
+        Prefix::Local => (TokenStream::new(), TokenStream::new()),
+    };
+    let type_path = quote! { #prefix #public_name };
 
     let body = match &input.data {
+        _ if via.is_some() => flavor.call(quote! { &::transmogrify::via_serde(self) }),
+        _ if from.is_some() => {
+            let repr = from.as_ref().unwrap();
+            let repr_value = flavor.call(quote! { &repr });
+            // The qualified type needs its parameters, if any; they're
+            // inferred from the argument.
+            let params = input
+                .generics
+                .params
+                .iter()
+                .filter(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+                .map(|_| quote! { _ })
+                .collect::<Vec<_>>();
+            let inferred = (!params.is_empty()).then(|| quote! { < #( #params ),* > });
+            quote! {
+                let repr = <#repr as ::core::convert::From<&Self>>::from(self);
+                let repr = #repr_value;
+                quote::quote! {
+                    <#type_path #inferred as #pound __core::convert::From<_>>::from(#pound repr)
+                }
+            }
+        }
+        syn::Data::Struct(s) if constructor.is_some() => {
+            // Skipped fields are omitted from the constructor's arguments.
+            let fields = s
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(ii, field)| {
+                    let (var, member) = match &field.ident {
+                        Some(ident) => (ident.clone(), ident.to_token_stream()),
+                        None => (
+                            format_ident!("value_{}", ii),
+                            syn::Index::from(ii).to_token_stream(),
+                        ),
+                    };
+                    let opts = MemberOpts::from_field_attrs(&field.attrs, flavor, &mut errors);
+                    (var, quote! { &self.#member }, opts)
+                })
+                .filter(|(_, _, opts)| opts.replacement.is_none())
+                .collect::<Vec<_>>();
+            let (lets, values) = transmogrify_fields(&fields, flavor);
+            quote! {
+                #( #lets )*
+                quote::quote! {
+                    #prefix #constructor(
+                        #( #values, )*
+                    )
+                }
+            }
+        }
         syn::Data::Struct(s) => {
             let fields = &s.fields;
             match fields {
                 syn::Fields::Named(fields) => {
-                    let field = fields
+                    let fields = fields
                         .named
                         .iter()
-                        .map(|syn::Field { ident, .. }| ident)
+                        .map(|field| {
+                            let ident = field.ident.as_ref().unwrap();
+                            let opts =
+                                MemberOpts::from_field_attrs(&field.attrs, flavor, &mut errors);
+                            (ident.clone(), quote! { &self.#ident }, opts)
+                        })
                         .collect::<Vec<_>>();
+                    let (lets, values) = transmogrify_fields(&fields, flavor);
+                    let field = fields.iter().map(|(ident, ..)| ident);
                     quote! {
-                        #(
-                            let #field = #transmogrify(&self.#field);
-                        )*
+                        #( #lets )*
                         quote::quote! {
-                            #prefix::#name {
-                                #( #field: #pound #field, )*
+                            #type_path {
+                                #( #field: #values, )*
                             }
                         }
                     }
@@ -129,31 +589,35 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
                 syn::Fields::Unnamed(fields) if fields.unnamed.is_empty() => {
                     quote! {
                         quote::quote! {
-                            #prefix::#name()
+                            #type_path()
                         }
                     }
                 }
                 syn::Fields::Unnamed(fields) => {
-                    let (index, var): (Vec<_>, Vec<_>) = fields
+                    let fields = fields
                         .unnamed
                         .iter()
                         .enumerate()
-                        .map(|(ii, _)| (syn::Index::from(ii), format_ident!("value_{}", ii)))
-                        .unzip();
+                        .map(|(ii, field)| {
+                            let index = syn::Index::from(ii);
+                            let opts =
+                                MemberOpts::from_field_attrs(&field.attrs, flavor, &mut errors);
+                            (format_ident!("value_{}", ii), quote! { &self.#index }, opts)
+                        })
+                        .collect::<Vec<_>>();
+                    let (lets, values) = transmogrify_fields(&fields, flavor);
                     quote! {
-                        #(
-                            let #var = #transmogrify(&self.#index);
-                        )*
+                        #( #lets )*
                         quote::quote! {
-                            #prefix::#name (
-                                #( #pound #var, )*
+                            #type_path (
+                                #( #values, )*
                             )
                         }
                     }
                 }
                 syn::Fields::Unit => quote! {
                     quote::quote!{
-                        #prefix::#name
+                        #type_path
                     }
                 },
             }
@@ -161,55 +625,109 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
             let variants = variants
                 .into_iter()
-                .map(|syn::Variant { ident, fields, .. }| match fields {
-                    syn::Fields::Named(fields) => {
-                        let field = fields
-                            .named
-                            .iter()
-                            .map(|syn::Field { ident, .. }| ident)
-                            .collect::<Vec<_>>();
-                        quote! {
-                            Self::#ident{ #( #field, )* } => {
-                                #(
-                                    let #field = #transmogrify(#field);
-                                )*
+                .map(|variant| {
+                    let syn::Variant {
+                        attrs,
+                        ident,
+                        fields,
+                        ..
+                    } = variant;
+                    let opts = MemberOpts::from_variant_attrs(attrs, flavor, &mut errors);
+                    let variant_path = match opts.path {
+                        Some(path) => path.to_token_stream(),
+                        None => quote! { #type_path::#ident },
+                    };
+                    if let Some(replacement) = opts.replacement {
+                        // A skipped variant emits the replacement value
+                        // regardless of its contents.
+                        return quote! {
+                            Self::#ident { .. } => {
                                 quote::quote! {
-                                    #prefix::#name::#ident {
-                                        #( #field: #pound #field, )*
-                                    }
+                                    #replacement
                                 }
                             }
-                        }
+                        };
                     }
-                    syn::Fields::Unnamed(fields) => {
-                        let field = fields
-                            .unnamed
-                            .iter()
-                            .enumerate()
-                            .map(|(ii, _)| format_ident!("x{}", ii))
-                            .collect::<Vec<_>>();
-                        quote! {
-                            Self::#ident( #( #field, )* ) => {
-                                #(
-                                    let #field = #transmogrify(#field);
-                                )*
-                                quote::quote! {
-                                    #prefix::#name::#ident (
-                                        #( #pound #field, )*
-                                    )
+                    match fields {
+                        syn::Fields::Named(fields) => {
+                            let fields = fields
+                                .named
+                                .iter()
+                                .map(|field| {
+                                    let ident = field.ident.as_ref().unwrap();
+                                    let opts = MemberOpts::from_field_attrs(
+                                        &field.attrs,
+                                        flavor,
+                                        &mut errors,
+                                    );
+                                    (ident.clone(), ident.to_token_stream(), opts)
+                                })
+                                .collect::<Vec<_>>();
+                            let (lets, values) = transmogrify_fields(&fields, flavor);
+                            let field = fields.iter().map(|(ident, ..)| ident);
+                            let pat = fields.iter().map(|(ident, _, opts)| {
+                                if opts.replacement.is_some() {
+                                    quote! { #ident: _ }
+                                } else {
+                                    quote! { #ident }
+                                }
+                            });
+                            quote! {
+                                Self::#ident{ #( #pat, )* } => {
+                                    #( #lets )*
+                                    quote::quote! {
+                                        #variant_path {
+                                            #( #field: #values, )*
+                                        }
+                                    }
                                 }
                             }
                         }
-                    }
-                    syn::Fields::Unit => quote! {
-                            Self::#ident => {
-                                quote::quote! {
-                                    #prefix::#name::#ident
+                        syn::Fields::Unnamed(fields) => {
+                            let fields = fields
+                                .unnamed
+                                .iter()
+                                .enumerate()
+                                .map(|(ii, field)| {
+                                    let var = format_ident!("x{}", ii);
+                                    let opts = MemberOpts::from_field_attrs(
+                                        &field.attrs,
+                                        flavor,
+                                        &mut errors,
+                                    );
+                                    (var.clone(), var.to_token_stream(), opts)
+                                })
+                                .collect::<Vec<_>>();
+                            let (lets, values) = transmogrify_fields(&fields, flavor);
+                            let pat = fields.iter().map(|(var, _, opts)| {
+                                if opts.replacement.is_some() {
+                                    quote! { _ }
+                                } else {
+                                    quote! { #var }
+                                }
+                            });
+                            quote! {
+                                Self::#ident( #( #pat, )* ) => {
+                                    #( #lets )*
+                                    quote::quote! {
+                                        #variant_path (
+                                            #( #values, )*
+                                        )
+                                    }
                                 }
                             }
+                        }
+                        syn::Fields::Unit => quote! {
+                                Self::#ident => {
+                                    quote::quote! {
+                                        #variant_path
+                                    }
+                                }
 
-                    },
-                });
+                        },
+                    }
+                })
+                .collect::<Vec<_>>();
             quote! {
                 match self {
                     #( #variants, )*
@@ -221,29 +739,176 @@ fn do_transmogrify_derive(input: DeriveInput) -> syn::Result<TokenStream> {
 
     let error_out = errors.into_iter().map(|x| x.into_compile_error());
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Absent an explicit bound, require that type parameters used in
+    // transmogrified fields implement Transmogrify, or for `via` and `from`,
+    // that the value can be serialized or converted to its representation.
+    let mut generics = input.generics.clone();
+    let predicates = bound.unwrap_or_else(|| {
+        let types = if from.is_some() || via.is_some() {
+            Vec::new()
+        } else {
+            transmogrified_types(&input.data)
+        };
+        input
+            .generics
+            .type_params()
+            .filter(|param| {
+                types
+                    .iter()
+                    .any(|ty| mentions(ty.to_token_stream(), &param.ident))
+            })
+            .map(|param| {
+                let ident = &param.ident;
+                let trait_path = flavor.trait_path();
+                syn::parse_quote! { #ident: #trait_path }
+            })
+            .chain(via.as_ref().map(|_| {
+                syn::parse_quote! { Self: ::serde::Serialize }
+            }))
+            .chain(from.iter().flat_map(|repr| {
+                let trait_path = flavor.trait_path();
+                [
+                    syn::parse_quote! {
+                        for<'__a> #repr: ::core::convert::From<&'__a Self> + #trait_path
+                    },
+                    syn::parse_quote! { Self: ::core::convert::From<#repr> },
+                ]
+            }))
+            .collect()
+    });
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    // Bind the path to `core` items if the output refers to any.
+    let core = mentions(body.clone(), &format_ident!("__core")).then(|| {
+        quote! {
+            let __core = __cx.core_path();
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let trait_path = flavor.trait_path();
+    let method = flavor.method(quote! {
+        #root
+        #core
+        #body
+    });
     Ok(quote! {
         #( #error_out )*
 
-        impl #impl_generics ::transmogrify::Transmogrify
+        impl #impl_generics #trait_path
             for #name #ty_generics #where_clause
         {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
-                #body
-            }
+            #method
         }
     })
 }
 
+/// Collect the types of all fields whose values will be transmogrified i.e.
+/// those that are neither skipped nor emitted by a `with` function.
+fn transmogrified_types(data: &syn::Data) -> Vec<&syn::Type> {
+    // Errors are reported during code generation so we ignore them here.
+    let mut ignored = Vec::new();
+    let fields = match data {
+        syn::Data::Struct(s) => s.fields.iter().collect::<Vec<_>>(),
+        syn::Data::Enum(e) => e
+            .variants
+            .iter()
+            .filter(|variant| {
+                MemberOpts::from_attrs(&variant.attrs, &mut ignored)
+                    .replacement
+                    .is_none()
+            })
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        syn::Data::Union(_) => Vec::new(),
+    };
+
+    fields
+        .into_iter()
+        .filter(|field| {
+            let opts = MemberOpts::from_attrs(&field.attrs, &mut ignored);
+            opts.replacement.is_none() && opts.with.is_none()
+        })
+        .map(|field| &field.ty)
+        .collect()
+}
+
+/// Get the name of the crate that a prefix path starts with, if it starts with
+/// one rather than with `crate`, `self`, or `super`.
+fn extern_crate(path: &syn::Path) -> Option<&syn::Ident> {
+    let first = &path.segments.first()?.ident;
+    let local = ["crate", "self", "super", "Self"]
+        .iter()
+        .any(|keyword| first == keyword);
+    (!local).then_some(first)
+}
+
+/// Returns true if the given identifier appears anywhere in the tokens.
+fn mentions(tokens: TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(i) => &i == ident,
+        proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Given a list of fields--each a variable name, an expression for a
+/// reference to the field's value, and its options--produce the `let`
+/// statements that transmogrify each field into a variable of that name and
+/// the tokens to interpolate into the generated `quote!` for each field.
+/// Fields with a `with` function are transmogrified by calling it. Skipped
+/// fields produce no `let` statement and interpolate their replacement tokens
+/// directly.
+fn transmogrify_fields(
+    fields: &[(syn::Ident, TokenStream, MemberOpts)],
+    flavor: Flavor,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+
+    fields
+        .iter()
+        .map(
+            |(var, access, opts)| match (&opts.replacement, &opts.with) {
+                (Some(replacement), _) => (TokenStream::new(), replacement.clone()),
+                (None, Some(with)) => (
+                    quote! {
+                        let #var = #with(#access, __cx);
+                    },
+                    quote! { #pound #var },
+                ),
+                (None, None) => (
+                    {
+                        let value = flavor.call(access.clone());
+                        quote! {
+                            let #var = #value;
+                        }
+                    },
+                    quote! { #pound #var },
+                ),
+            },
+        )
+        .unzip()
+}
+
 #[cfg(test)]
 mod tests {
     use heck::ToSnakeCase;
     use proc_macro2::TokenStream;
     use quote::quote;
 
-    use crate::do_transmogrify_derive;
+    use crate::{do_transmogrify_derive, Flavor};
 
     fn type_tester(item: TokenStream) {
+        flavor_tester(item, Flavor::Runtime)
+    }
+
+    fn const_type_tester(item: TokenStream) {
+        flavor_tester(item, Flavor::Const)
+    }
+
+    fn flavor_tester(item: TokenStream, flavor: Flavor) {
         let input: syn::DeriveInput = syn::parse_quote! {
             #item
         };
@@ -253,7 +918,7 @@ mod tests {
             input.ident.to_string().to_snake_case()
         );
 
-        let output = do_transmogrify_derive(input.clone()).expect("invalid type");
+        let output = do_transmogrify_derive(input.clone(), flavor).expect("invalid type");
 
         let file = syn::parse_quote! {
             #input
@@ -319,10 +984,199 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_skip_fields() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate)]
+            pub struct SkipFields {
+                pub name: String,
+                #[transmogrify(skip)]
+                pub cache: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+                #[transmogrify(default = Span::call_site())]
+                pub span: Span,
+            }
+        });
+    }
+
+    #[test]
+    fn test_skip_variants() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate)]
+            pub enum SkipVariants {
+                A(String, #[transmogrify(default)] Cache),
+                B {
+                    name: String,
+                    #[transmogrify(default = Span::call_site())]
+                    span: Span,
+                },
+                #[transmogrify(skip)]
+                C(Cache),
+                #[transmogrify(default = foo_crate::SkipVariants::A(String::new(), Cache::new()))]
+                D,
+            }
+        });
+    }
+
+    #[test]
+    fn test_with_fields() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate)]
+            pub struct WithFields {
+                pub name: String,
+                #[transmogrify(with = foreign::emit_url)]
+                pub url: url::Url,
+            }
+        });
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate)]
+            pub struct GenericStruct<'a, T, U, V: Clone>
+            where
+                V: Default,
+            {
+                pub value: Option<T>,
+                pub values: Vec<(&'a str, V)>,
+                #[transmogrify(skip)]
+                pub marker: std::marker::PhantomData<U>,
+            }
+        });
+    }
+
+    #[test]
+    fn test_generic_enum_bound() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate, bound = "T: Clone + ::transmogrify::Transmogrify")]
+            pub enum GenericEnumBound<T> {
+                A(std::rc::Rc<T>),
+                B,
+            }
+        });
+    }
+
+    #[test]
+    fn test_constructor_struct() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate, constructor = ConstructorStruct::new)]
+            pub struct ConstructorStruct {
+                name: String,
+                #[transmogrify(skip)]
+                len: usize,
+                values: Vec<u32>,
+            }
+        });
+    }
+
+    #[test]
+    fn test_from_repr() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate, from = FromReprRepr)]
+            pub enum FromRepr {
+                Private(Secret),
+            }
+        });
+    }
+
+    #[test]
+    fn test_renamed_enum() {
+        type_tester(quote! {
+            #[transmogrify(prefix = foo_crate, rename = PublicName)]
+            pub enum RenamedEnum {
+                A,
+                #[transmogrify(path = foo_crate::B)]
+                B(String),
+                #[transmogrify(path = foo_crate::reexport::C)]
+                C { foo: String },
+            }
+        });
+    }
+
+    #[test]
+    fn test_local_struct() {
+        type_tester(quote! {
+            #[transmogrify(local)]
+            pub struct LocalStruct {
+                pub foo: String,
+            }
+        });
+    }
+
+    #[test]
+    fn test_crate_root_enum() {
+        type_tester(quote! {
+            #[transmogrify(prefix = $crate)]
+            pub enum CrateRootEnum {
+                A,
+                B(String),
+            }
+        });
+    }
+
     #[test]
     fn test_error_no_attr() {
         type_tester(quote! {
             pub struct ErrorNoAttr {}
         });
     }
+
+    #[test]
+    fn test_const_struct() {
+        const_type_tester(quote! {
+            #[transmogrify(prefix = foo_crate)]
+            pub struct ConstStruct<T> {
+                pub name: &'static str,
+                pub values: &'static [T],
+                #[transmogrify(default = 0)]
+                pub count: usize,
+            }
+        });
+    }
+
+    #[test]
+    fn test_const_enum() {
+        const_type_tester(quote! {
+            #[transmogrify(prefix = $crate)]
+            pub enum ConstEnum {
+                A,
+                B(Option<u32>),
+                #[transmogrify(default = ConstEnum::A)]
+                C { value: String },
+            }
+        });
+    }
+
+    #[test]
+    fn test_error_const_unsupported() {
+        const_type_tester(quote! {
+            #[transmogrify(local)]
+            pub struct ErrorConstUnsupported {
+                #[transmogrify(skip)]
+                pub a: u32,
+                #[transmogrify(with = emit_b)]
+                pub b: u32,
+            }
+        });
+    }
+
+    #[test]
+    fn test_via_json() {
+        type_tester(quote! {
+            #[transmogrify(via = "json")]
+            pub struct ViaJson<T> {
+                table: Vec<T>,
+            }
+        });
+    }
+
+    #[test]
+    fn test_error_via_format() {
+        type_tester(quote! {
+            #[transmogrify(via = "bincode")]
+            pub struct ErrorViaFormat {
+                pub table: Vec<u32>,
+            }
+        });
+    }
 }
@@ -0,0 +1,38 @@
+#[transmogrify(prefix = $crate)]
+pub enum ConstEnum {
+    A,
+    B(Option<u32>),
+    #[transmogrify(default = ConstEnum::A)]
+    C { value: String },
+}
+impl ::transmogrify::TransmogrifyConst for ConstEnum {
+    fn transmogrify_const_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> ::core::result::Result<proc_macro2::TokenStream, ::transmogrify::ConstError> {
+        ::core::result::Result::Ok({
+            let __crate_root = __cx.crate_root();
+            match self {
+                Self::A => {
+                    quote::quote! {
+                        #__crate_root::ConstEnum::A
+                    }
+                }
+                Self::B(x0) => {
+                    let x0 = ::transmogrify::TransmogrifyConst::transmogrify_const_with(
+                        x0,
+                        __cx,
+                    )?;
+                    quote::quote! {
+                        #__crate_root::ConstEnum::B(#x0,)
+                    }
+                }
+                Self::C { .. } => {
+                    quote::quote! {
+                        ConstEnum::A
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,31 @@
+#[transmogrify(prefix = foo_crate)]
+pub struct ConstStruct<T> {
+    pub name: &'static str,
+    pub values: &'static [T],
+    #[transmogrify(default = 0)]
+    pub count: usize,
+}
+impl<T> ::transmogrify::TransmogrifyConst for ConstStruct<T>
+where
+    T: ::transmogrify::TransmogrifyConst,
+{
+    fn transmogrify_const_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> ::core::result::Result<proc_macro2::TokenStream, ::transmogrify::ConstError> {
+        ::core::result::Result::Ok({
+            let __crate = __cx.crate_path("foo_crate");
+            let name = ::transmogrify::TransmogrifyConst::transmogrify_const_with(
+                &self.name,
+                __cx,
+            )?;
+            let values = ::transmogrify::TransmogrifyConst::transmogrify_const_with(
+                &self.values,
+                __cx,
+            )?;
+            quote::quote! {
+                #__crate::ConstStruct { name : #name, values : #values, count : 0, }
+            }
+        })
+    }
+}
@@ -0,0 +1,20 @@
+#[transmogrify(prefix = foo_crate, constructor = ConstructorStruct::new)]
+pub struct ConstructorStruct {
+    name: String,
+    #[transmogrify(skip)]
+    len: usize,
+    values: Vec<u32>,
+}
+impl ::transmogrify::Transmogrify for ConstructorStruct {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let name = __cx.emit(&self.name);
+        let values = __cx.emit(&self.values);
+        quote::quote! {
+            #__crate::ConstructorStruct::new(#name, #values,)
+        }
+    }
+}
@@ -0,0 +1,26 @@
+#[transmogrify(prefix = $crate)]
+pub enum CrateRootEnum {
+    A,
+    B(String),
+}
+impl ::transmogrify::Transmogrify for CrateRootEnum {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate_root = __cx.crate_root();
+        match self {
+            Self::A => {
+                quote::quote! {
+                    #__crate_root::CrateRootEnum::A
+                }
+            }
+            Self::B(x0) => {
+                let x0 = __cx.emit(x0);
+                quote::quote! {
+                    #__crate_root::CrateRootEnum::B(#x0,)
+                }
+            }
+        }
+    }
+}
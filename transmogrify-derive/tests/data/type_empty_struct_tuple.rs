@@ -1,9 +1,13 @@
 #[transmogrify(prefix = foo_crate)]
 pub struct EmptyStructTuple();
 impl ::transmogrify::Transmogrify for EmptyStructTuple {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
         quote::quote! {
-            foo_crate::EmptyStructTuple()
+            #__crate::EmptyStructTuple()
         }
     }
 }
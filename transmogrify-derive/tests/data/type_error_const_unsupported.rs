@@ -0,0 +1,28 @@
+#[transmogrify(local)]
+pub struct ErrorConstUnsupported {
+    #[transmogrify(skip)]
+    pub a: u32,
+    #[transmogrify(with = emit_b)]
+    pub b: u32,
+}
+::core::compile_error! {
+    "TransmogrifyConst requires a const replacement: `#[transmogrify(default = <expr>)]`"
+}
+::core::compile_error! {
+    "`with` is not supported by TransmogrifyConst"
+}
+impl ::transmogrify::TransmogrifyConst for ErrorConstUnsupported {
+    fn transmogrify_const_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> ::core::result::Result<proc_macro2::TokenStream, ::transmogrify::ConstError> {
+        ::core::result::Result::Ok({
+            let __core = __cx.core_path();
+            let b = emit_b(&self.b, __cx);
+            quote::quote! {
+                ErrorConstUnsupported { a : #__core::default::Default::default(), b : #b,
+                }
+            }
+        })
+    }
+}
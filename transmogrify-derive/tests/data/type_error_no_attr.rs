@@ -1,11 +1,14 @@
 pub struct ErrorNoAttr {}
 ::core::compile_error! {
-    "must specify a path prefix #[transmogrify(prefix = <path>)]"
+    "must specify a path prefix #[transmogrify(prefix = <path>)] or #[transmogrify(local)]"
 }
 impl ::transmogrify::Transmogrify for ErrorNoAttr {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
         quote::quote! {
-            ::ErrorNoAttr {}
+            ErrorNoAttr {}
         }
     }
 }
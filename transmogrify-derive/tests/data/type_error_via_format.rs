@@ -0,0 +1,18 @@
+#[transmogrify(via = "bincode")]
+pub struct ErrorViaFormat {
+    pub table: Vec<u32>,
+}
+::core::compile_error! {
+    "unsupported format; expected \"json\""
+}
+impl ::transmogrify::Transmogrify for ErrorViaFormat {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let table = __cx.emit(&self.table);
+        quote::quote! {
+            ErrorViaFormat { table : #table, }
+        }
+    }
+}
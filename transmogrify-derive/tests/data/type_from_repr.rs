@@ -0,0 +1,23 @@
+#[transmogrify(prefix = foo_crate, from = FromReprRepr)]
+pub enum FromRepr {
+    Private(Secret),
+}
+impl ::transmogrify::Transmogrify for FromRepr
+where
+    for<'__a> FromReprRepr: ::core::convert::From<&'__a Self>
+        + ::transmogrify::Transmogrify,
+    Self: ::core::convert::From<FromReprRepr>,
+{
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let __core = __cx.core_path();
+        let repr = <FromReprRepr as ::core::convert::From<&Self>>::from(self);
+        let repr = __cx.emit(&repr);
+        quote::quote! {
+            < #__crate::FromRepr as #__core::convert::From < _ >> ::from(#repr)
+        }
+    }
+}
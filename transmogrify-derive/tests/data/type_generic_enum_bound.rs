@@ -0,0 +1,29 @@
+#[transmogrify(prefix = foo_crate, bound = "T: Clone + ::transmogrify::Transmogrify")]
+pub enum GenericEnumBound<T> {
+    A(std::rc::Rc<T>),
+    B,
+}
+impl<T> ::transmogrify::Transmogrify for GenericEnumBound<T>
+where
+    T: Clone + ::transmogrify::Transmogrify,
+{
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        match self {
+            Self::A(x0) => {
+                let x0 = __cx.emit(x0);
+                quote::quote! {
+                    #__crate::GenericEnumBound::A(#x0,)
+                }
+            }
+            Self::B => {
+                quote::quote! {
+                    #__crate::GenericEnumBound::B
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#[transmogrify(prefix = foo_crate)]
+pub struct GenericStruct<'a, T, U, V: Clone>
+where
+    V: Default,
+{
+    pub value: Option<T>,
+    pub values: Vec<(&'a str, V)>,
+    #[transmogrify(skip)]
+    pub marker: std::marker::PhantomData<U>,
+}
+impl<'a, T, U, V: Clone> ::transmogrify::Transmogrify for GenericStruct<'a, T, U, V>
+where
+    V: Default,
+    T: ::transmogrify::Transmogrify,
+    V: ::transmogrify::Transmogrify,
+{
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let __core = __cx.core_path();
+        let value = __cx.emit(&self.value);
+        let values = __cx.emit(&self.values);
+        quote::quote! {
+            #__crate::GenericStruct { value : #value, values : #values, marker :
+            #__core::default::Default::default(), }
+        }
+    }
+}
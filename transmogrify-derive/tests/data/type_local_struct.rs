@@ -0,0 +1,15 @@
+#[transmogrify(local)]
+pub struct LocalStruct {
+    pub foo: String,
+}
+impl ::transmogrify::Transmogrify for LocalStruct {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let foo = __cx.emit(&self.foo);
+        quote::quote! {
+            LocalStruct { foo : #foo, }
+        }
+    }
+}
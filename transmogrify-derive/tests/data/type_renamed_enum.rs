@@ -0,0 +1,35 @@
+#[transmogrify(prefix = foo_crate, rename = PublicName)]
+pub enum RenamedEnum {
+    A,
+    #[transmogrify(path = foo_crate::B)]
+    B(String),
+    #[transmogrify(path = foo_crate::reexport::C)]
+    C { foo: String },
+}
+impl ::transmogrify::Transmogrify for RenamedEnum {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        match self {
+            Self::A => {
+                quote::quote! {
+                    #__crate::PublicName::A
+                }
+            }
+            Self::B(x0) => {
+                let x0 = __cx.emit(x0);
+                quote::quote! {
+                    foo_crate::B(#x0,)
+                }
+            }
+            Self::C { foo } => {
+                let foo = __cx.emit(foo);
+                quote::quote! {
+                    foo_crate::reexport::C { foo : #foo, }
+                }
+            }
+        }
+    }
+}
@@ -6,28 +6,32 @@ pub enum SimpleEnum {
     D { foo: String },
 }
 impl ::transmogrify::Transmogrify for SimpleEnum {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
         match self {
             Self::A => {
                 quote::quote! {
-                    foo_crate::SimpleEnum::A
+                    #__crate::SimpleEnum::A
                 }
             }
             Self::B() => {
                 quote::quote! {
-                    foo_crate::SimpleEnum::B()
+                    #__crate::SimpleEnum::B()
                 }
             }
             Self::C(x0) => {
-                let x0 = ::transmogrify::Transmogrify::transmogrify(x0);
+                let x0 = __cx.emit(x0);
                 quote::quote! {
-                    foo_crate::SimpleEnum::C(#x0,)
+                    #__crate::SimpleEnum::C(#x0,)
                 }
             }
             Self::D { foo } => {
-                let foo = ::transmogrify::Transmogrify::transmogrify(foo);
+                let foo = __cx.emit(foo);
                 quote::quote! {
-                    foo_crate::SimpleEnum::D { foo : #foo, }
+                    #__crate::SimpleEnum::D { foo : #foo, }
                 }
             }
         }
@@ -3,10 +3,14 @@ pub struct SimpleStruct {
     pub foo: String,
 }
 impl ::transmogrify::Transmogrify for SimpleStruct {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        let foo = ::transmogrify::Transmogrify::transmogrify(&self.foo);
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let foo = __cx.emit(&self.foo);
         quote::quote! {
-            foo_crate::SimpleStruct { foo : #foo, }
+            #__crate::SimpleStruct { foo : #foo, }
         }
     }
 }
@@ -0,0 +1,22 @@
+#[transmogrify(prefix = foo_crate)]
+pub struct SkipFields {
+    pub name: String,
+    #[transmogrify(skip)]
+    pub cache: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+    #[transmogrify(default = Span::call_site())]
+    pub span: Span,
+}
+impl ::transmogrify::Transmogrify for SkipFields {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let __core = __cx.core_path();
+        let name = __cx.emit(&self.name);
+        quote::quote! {
+            #__crate::SkipFields { name : #name, cache :
+            #__core::default::Default::default(), span : Span::call_site(), }
+        }
+    }
+}
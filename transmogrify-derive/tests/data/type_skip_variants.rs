@@ -0,0 +1,42 @@
+#[transmogrify(prefix = foo_crate)]
+pub enum SkipVariants {
+    A(String, #[transmogrify(default)] Cache),
+    B { name: String, #[transmogrify(default = Span::call_site())] span: Span },
+    #[transmogrify(skip)]
+    C(Cache),
+    #[transmogrify(default = foo_crate::SkipVariants::A(String::new(), Cache::new()))]
+    D,
+}
+impl ::transmogrify::Transmogrify for SkipVariants {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let __core = __cx.core_path();
+        match self {
+            Self::A(x0, _) => {
+                let x0 = __cx.emit(x0);
+                quote::quote! {
+                    #__crate::SkipVariants::A(#x0, #__core::default::Default::default(),)
+                }
+            }
+            Self::B { name, span: _ } => {
+                let name = __cx.emit(name);
+                quote::quote! {
+                    #__crate::SkipVariants::B { name : #name, span : Span::call_site(), }
+                }
+            }
+            Self::C { .. } => {
+                quote::quote! {
+                    #__core::default::Default::default()
+                }
+            }
+            Self::D { .. } => {
+                quote::quote! {
+                    foo_crate::SkipVariants::A(String::new(), Cache::new())
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,14 @@
 #[transmogrify(prefix = foo_crate)]
 pub struct TupleStruct(pub String);
 impl ::transmogrify::Transmogrify for TupleStruct {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        let value_0 = ::transmogrify::Transmogrify::transmogrify(&self.0);
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let value_0 = __cx.emit(&self.0);
         quote::quote! {
-            foo_crate::TupleStruct(#value_0,)
+            #__crate::TupleStruct(#value_0,)
         }
     }
 }
@@ -0,0 +1,15 @@
+#[transmogrify(via = "json")]
+pub struct ViaJson<T> {
+    table: Vec<T>,
+}
+impl<T> ::transmogrify::Transmogrify for ViaJson<T>
+where
+    Self: ::serde::Serialize,
+{
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        __cx.emit(&::transmogrify::via_serde(self))
+    }
+}
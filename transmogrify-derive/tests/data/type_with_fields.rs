@@ -0,0 +1,19 @@
+#[transmogrify(prefix = foo_crate)]
+pub struct WithFields {
+    pub name: String,
+    #[transmogrify(with = foreign::emit_url)]
+    pub url: url::Url,
+}
+impl ::transmogrify::Transmogrify for WithFields {
+    fn transmogrify_with(
+        &self,
+        __cx: &::transmogrify::Context,
+    ) -> proc_macro2::TokenStream {
+        let __crate = __cx.crate_path("foo_crate");
+        let name = __cx.emit(&self.name);
+        let url = foreign::emit_url(&self.url, __cx);
+        quote::quote! {
+            #__crate::WithFields { name : #name, url : #url, }
+        }
+    }
+}
@@ -46,7 +46,7 @@ fn do_transmogrify(item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::T
                 format!(
                     "{}\n{}",
                     "expected a single item of the form",
-                    "fn transmogrify(&self) -> proc_macro2::TokenStream {}"
+                    "fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {}"
                 ),
             )
             .into_compile_error(),
@@ -59,12 +59,12 @@ fn do_transmogrify(item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::T
             format!(
                 "{}\n{}",
                 "expected a single item of the form",
-                "fn transmogrify(&self) -> proc_macro2::TokenStream {}"
+                "fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {}"
             ),
         )
         .into_compile_error();
         item_impl.items.push(syn::parse_quote! {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
+            fn transmogrify_with(&self, _cx: &Context) -> proc_macro2::TokenStream {
                 todo!()
             }
         });
@@ -74,18 +74,25 @@ fn do_transmogrify(item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::T
         });
     };
 
-    // TODO Confirm type signature
+    // TODO Confirm the rest of the type signature
+    let Some(cx) = get_context_arg(&t_fn.sig) else {
+        return Err(syn::Error::new_spanned(
+            &t_fn.sig,
+            "expected a signature of the form \
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream",
+        ));
+    };
     let block = &mut t_fn.block;
 
     if let Some(Stmt::Expr(Expr::Match(expr), _)) = block.stmts.first_mut() {
         // We assume a single statement of the form `match self { .. }``
-        translate_match_expr(self_ty, expr)
+        translate_match_expr(self_ty, &cx, expr)
     } else if let Some(Stmt::Local(_)) = block.stmts.first() {
         let stmts = std::mem::take(&mut block.stmts);
         let Some(Stmt::Local(local)) = stmts.first() else {
             unreachable!()
         };
-        if let Err(e) = translate_local_expr(self_ty, block, local) {
+        if let Err(e) = translate_local_expr(self_ty, &cx, block, local) {
             block.stmts = stmts;
             soft_errors.push(e.into_compile_error());
         }
@@ -102,6 +109,50 @@ fn do_transmogrify(item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::T
     })
 }
 
+/// Get the name of the `Context` argument of `fn transmogrify_with`.
+fn get_context_arg(sig: &syn::Signature) -> Option<syn::Ident> {
+    match sig.inputs.iter().nth(1)? {
+        syn::FnArg::Typed(syn::PatType { pat, .. }) => match pat.as_ref() {
+            syn::Pat::Ident(syn::PatIdent { ident, .. }) => Some(ident.clone()),
+            _ => None,
+        },
+        syn::FnArg::Receiver(_) => None,
+    }
+}
+
+/// If the type is from another crate i.e. its path has multiple segments
+/// such as `schemars::schema::Schema`, get the name of that crate. Paths that
+/// start with `crate`, `self`, `super`, or `Self` are local and are emitted as
+/// written; types in local modules must be named that way to be told apart
+/// from those in other crates.
+fn get_foreign_crate(self_ty: &syn::Type) -> Option<&syn::Ident> {
+    match self_ty {
+        syn::Type::Path(p) if p.path.leading_colon.is_none() && p.path.segments.len() > 1 => {
+            let first = &p.path.segments.first()?.ident;
+            let local = ["crate", "self", "super", "Self"]
+                .iter()
+                .any(|keyword| first == keyword);
+            (!local).then_some(first)
+        }
+        _ => None,
+    }
+}
+
+/// For types from another crate, produce a statement that looks up the path
+/// to emit for that crate in the context. Emitted paths use the result (see
+/// [`xxx_path`]).
+fn crate_lookup(self_ty: &syn::Type, cx: &syn::Ident) -> proc_macro2::TokenStream {
+    match get_foreign_crate(self_ty) {
+        Some(krate) => {
+            let name = krate.to_string();
+            quote::quote! {
+                let __crate = #cx.crate_path(#name);
+            }
+        }
+        None => proc_macro2::TokenStream::new(),
+    }
+}
+
 fn get_self_type(self_ty: &syn::Type) -> syn::Type {
     match self_ty {
         syn::Type::Path(p) => {
@@ -128,6 +179,7 @@ fn get_self_type(self_ty: &syn::Type) -> syn::Type {
 
 fn translate_local_expr(
     self_ty: syn::Type,
+    cx: &syn::Ident,
     block: &mut syn::Block,
     local: &syn::Local,
 ) -> syn::Result<()> {
@@ -161,9 +213,11 @@ fn translate_local_expr(
             // of the member.
             let transitive_transmogrification = members.iter().map(|member| {
                 quote::quote_spanned! {member.span()=>
-                    let #member = #member.transmogrify()
+                    let #member = #cx.emit(#member)
                 }
             });
+            let lookup = crate_lookup(&self_ty, cx);
+            let path = xxx_path(&self_ty, &self_ty);
             // Replace the old body which should be something like:
             // {
             //     let Self { ... } = self;
@@ -172,11 +226,12 @@ fn translate_local_expr(
             *block = syn::parse_quote! {
                 {
                     #local
+                    #lookup
                     #(
                         #transitive_transmogrification;
                     )*
                     quote::quote!{
-                        #self_ty {
+                        #path {
                             #( #members: #pound #members, )*
                         }
                     }
@@ -191,14 +246,26 @@ fn translate_local_expr(
     }
 }
 
-fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
+fn translate_match_expr(self_ty: syn::Type, cx: &syn::Ident, expr: &mut syn::ExprMatch) {
+    let lookup = crate_lookup(&self_ty, cx);
     expr.arms.iter_mut().for_each(|arm| {
         match &mut arm.pat {
             syn::Pat::Path(syn::PatPath { path, .. }) => {
-                let path = xxx_path(path, &self_ty);
-                arm.body = syn::parse_quote! {
-                    quote::quote!{
-                        #path
+                let path = xxx_path(&resolve_path(path, &self_ty), &self_ty);
+                arm.body = if lookup.is_empty() {
+                    syn::parse_quote! {
+                        quote::quote!{
+                            #path
+                        }
+                    }
+                } else {
+                    syn::parse_quote! {
+                        {
+                            #lookup
+                            quote::quote!{
+                                #path
+                            }
+                        }
                     }
                 };
             }
@@ -214,7 +281,7 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
                 assert!(attrs.is_empty());
                 assert!(qself.is_none());
 
-                let path = xxx_path(path, &self_ty);
+                let path = xxx_path(&resolve_path(path, &self_ty), &self_ty);
 
                 let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
 
@@ -227,7 +294,7 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
                     .map(|(i, pat)| {
                         let value = format_ident!("value_{}", i);
                         quote::quote_spanned! {pat.span()=>
-                            let #value = #value.transmogrify()
+                            let #value = #cx.emit(#value)
                         }
                     })
                     .collect::<Vec<_>>();
@@ -245,6 +312,7 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
                 let xxx = elems.iter().collect::<Vec<_>>();
                 arm.body = syn::parse_quote! {
                     {
+                        #lookup
                         #(
                             #transitive_transmogrification;
                         )*
@@ -267,7 +335,7 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
                 assert!(qself.is_none());
                 assert!(rest.is_none());
 
-                let path = xxx_path(path, &self_ty);
+                let path = xxx_path(&resolve_path(path, &self_ty), &self_ty);
 
                 let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
                 let members = fields
@@ -287,12 +355,13 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
                 // of the member.
                 let transitive_transmogrification = members.iter().map(|member| {
                     quote::quote_spanned! {member.span()=>
-                        let #member = #member.transmogrify()
+                        let #member = #cx.emit(#member)
                     }
                 });
                 // Replace the old body which should be something like `todo!()`
                 arm.body = syn::parse_quote! {
                     {
+                        #lookup
                         #(
                             #transitive_transmogrification;
                         )*
@@ -323,20 +392,36 @@ fn translate_match_expr(self_ty: syn::Type, expr: &mut syn::ExprMatch) {
     });
 }
 
-fn xxx_path(path: &mut syn::Path, self_ty: &syn::Type) -> proc_macro2::TokenStream {
+/// Replace a leading `Self` in the path with the self type.
+fn resolve_path(path: &syn::Path, self_ty: &syn::Type) -> syn::Path {
     let mut xxx = path.segments.iter();
     let Some(first) = xxx.next() else {
         unreachable!();
     };
 
     if first.arguments.is_none() && first.ident == "Self" {
-        quote::quote! {
+        syn::parse_quote! {
             #self_ty #( ::#xxx)*
         }
     } else {
-        quote::quote! {
-            #first #( ::#xxx)*
+        path.clone()
+    }
+}
+
+/// Produce the tokens to emit for the path, replacing the name of the self
+/// type's crate with the path looked up from the context (see
+/// [`crate_lookup`]).
+fn xxx_path<T: ToTokens>(path: &T, self_ty: &syn::Type) -> proc_macro2::TokenStream {
+    let path = syn::parse2::<syn::Path>(path.to_token_stream()).unwrap();
+    let mut xxx = path.segments.iter();
+    match (get_foreign_crate(self_ty), xxx.next()) {
+        (Some(krate), Some(first)) if path.leading_colon.is_none() && &first.ident == krate => {
+            let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+            quote::quote! {
+                #pound __crate #( ::#xxx )*
+            }
         }
+        _ => path.to_token_stream(),
     }
 }
 
@@ -350,7 +435,7 @@ mod tests {
     fn test_transmogrify_enum() {
         let input = quote! {
             impl Transmogrify for TestEnum {
-                fn transmogrify(&self) -> proc_macro2::TokenStream {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                     match self {
                         Self::A => todo!(),
                         TestEnum::B(_, _) => todo!(),
@@ -364,7 +449,7 @@ mod tests {
         let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
         let expected = quote! {
             impl Transmogrify for TestEnum {
-                fn transmogrify(&self) -> proc_macro2::TokenStream {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                     match self {
                         Self::A => {
                             quote::quote! {
@@ -372,15 +457,15 @@ mod tests {
                             }
                         }
                         TestEnum::B(value_0, value_1) => {
-                            let value_0 = value_0.transmogrify();
-                            let value_1 = value_1.transmogrify();
+                            let value_0 = cx.emit(value_0);
+                            let value_1 = cx.emit(value_1);
                             quote::quote! {
                                 TestEnum::B(#pound value_0, #pound value_1,)
                             }
                         }
                         TestEnum::C { a, b } => {
-                            let a = a.transmogrify();
-                            let b = b.transmogrify();
+                            let a = cx.emit(a);
+                            let b = cx.emit(b);
                             quote::quote! {
                                 TestEnum::C {
                                     a: #pound a,
@@ -403,7 +488,7 @@ mod tests {
     fn test_transmogrify_struct() {
         let input = quote! {
             impl Transmogrify for TestStruct {
-                fn transmogrify(&self) -> proc_macro2::TokenStream {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                     let Self { a, b } = self;
                 }
             }
@@ -413,10 +498,10 @@ mod tests {
         let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
         let expected = quote! {
             impl Transmogrify for TestStruct {
-                fn transmogrify(&self) -> proc_macro2::TokenStream {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                     let Self { a, b } = self;
-                    let a = a.transmogrify();
-                    let b = b.transmogrify();
+                    let a = cx.emit(a);
+                    let b = cx.emit(b);
                     quote::quote! {
                         TestStruct { a: #pound a, b: #pound b, }
                     }
@@ -429,4 +514,112 @@ mod tests {
 
         pretty_assertions::assert_eq!(output_str, expected_str);
     }
+
+    #[test]
+    fn test_transmogrify_foreign() {
+        let input = quote! {
+            impl Transmogrify for foo::Bar {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    match self {
+                        Self::A => todo!(),
+                        foo::Bar::B(_) => todo!(),
+                    }
+                }
+            }
+        };
+        let output = do_transmogrify(input).unwrap();
+
+        let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+        let expected = quote! {
+            impl Transmogrify for foo::Bar {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    match self {
+                        Self::A => {
+                            let __crate = cx.crate_path("foo");
+                            quote::quote! {
+                                #pound __crate::Bar::A
+                            }
+                        }
+                        foo::Bar::B(value_0) => {
+                            let __crate = cx.crate_path("foo");
+                            let value_0 = cx.emit(value_0);
+                            quote::quote! {
+                                #pound __crate::Bar::B(#pound value_0,)
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        let output_str = prettyplease::unparse(&syn::parse2(output).unwrap());
+        let expected_str = prettyplease::unparse(&syn::parse2(expected).unwrap());
+
+        pretty_assertions::assert_eq!(output_str, expected_str);
+    }
+
+    #[test]
+    fn test_transmogrify_local_path() {
+        let input = quote! {
+            impl Transmogrify for crate::inner::Foo {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    match self {
+                        Self::A => todo!(),
+                        crate::inner::Foo::B(_) => todo!(),
+                    }
+                }
+            }
+        };
+        let output = do_transmogrify(input).unwrap();
+
+        let pound = proc_macro2::Punct::new('#', proc_macro2::Spacing::Joint);
+        let expected = quote! {
+            impl Transmogrify for crate::inner::Foo {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    match self {
+                        Self::A => quote::quote! {
+                            crate::inner::Foo::A
+                        },
+                        crate::inner::Foo::B(value_0) => {
+                            let value_0 = cx.emit(value_0);
+                            quote::quote! {
+                                crate::inner::Foo::B(#pound value_0,)
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        let output_str = prettyplease::unparse(&syn::parse2(output).unwrap());
+        let expected_str = prettyplease::unparse(&syn::parse2(expected).unwrap());
+
+        pretty_assertions::assert_eq!(output_str, expected_str);
+
+        let input = quote! {
+            impl Transmogrify for super::Bar {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    let Self { a } = self;
+                }
+            }
+        };
+        let output = do_transmogrify(input).unwrap();
+
+        let expected = quote! {
+            impl Transmogrify for super::Bar {
+                fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                    let Self { a } = self;
+                    let a = cx.emit(a);
+                    quote::quote! {
+                        super::Bar { a: #pound a, }
+                    }
+                }
+            }
+        };
+
+        let output_str = prettyplease::unparse(&syn::parse2(output).unwrap());
+        let expected_str = prettyplease::unparse(&syn::parse2(expected).unwrap());
+
+        pretty_assertions::assert_eq!(output_str, expected_str);
+    }
 }
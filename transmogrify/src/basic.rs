@@ -1,35 +1,107 @@
-use crate::Transmogrify;
+use crate::{
+    dag::emitted_text, shared::share, ConstError, Context, Transmogrify, TransmogrifyConst,
+};
+
+use std::{borrow::Cow, sync::TryLockError};
 
 use quote::quote;
 
 impl<T: Transmogrify> Transmogrify for Option<T> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        match self.as_ref().map(Transmogrify::transmogrify) {
-            Some(t) => quote! { Some(#t) },
-            None => quote! { None },
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        match self.as_ref().map(|t| cx.emit(t)) {
+            Some(t) => quote! { #core::option::Option::Some(#t) },
+            None => quote! { #core::option::Option::None },
+        }
+    }
+}
+
+impl<T: TransmogrifyConst> TransmogrifyConst for Option<T> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let core = cx.core_path();
+        match self {
+            Some(t) => {
+                let t = t.transmogrify_const_with(cx)?;
+                Ok(quote! { #core::option::Option::Some(#t) })
+            }
+            None => Ok(quote! { #core::option::Option::None }),
         }
     }
 }
 
 impl<T: Transmogrify> Transmogrify for Box<T> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        let t = self.as_ref().transmogrify();
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let alloc = cx.alloc_path();
+        let t = cx.emit(self.as_ref());
         quote! {
-            Box::new(#t)
+            #alloc::boxed::Box::new(#t)
+        }
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for Box<[T]> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            quote! {
+                <#alloc::boxed::Box<[_]> as #core::default::Default>::default()
+            }
+        } else {
+            let items = self.iter().map(|item| cx.emit(item));
+            quote! {
+                <#alloc::boxed::Box<[_]> as #core::convert::From<_>>::from([
+                    #( #items, )*
+                ])
+            }
+        }
+    }
+}
+
+macro_rules! str_pointer_impl {
+    ($module:ident, $ty:ident) => {
+        impl Transmogrify for std::$module::$ty<str> {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let core = cx.core_path();
+                let alloc = cx.alloc_path();
+                let value: &str = self;
+                quote! {
+                    <#alloc::$module::$ty<str> as #core::convert::From<&str>>::from(#value)
+                }
+            }
         }
+    };
+}
+
+str_pointer_impl!(boxed, Box);
+str_pointer_impl!(rc, Rc);
+str_pointer_impl!(sync, Arc);
+
+impl<T: TransmogrifyConst> TransmogrifyConst for Box<T> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let t = self.as_ref().transmogrify_const_with(cx)?;
+        Ok(quote! { &#t })
     }
 }
 
 impl<T: Transmogrify> Transmogrify for Vec<T> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
         if self.is_empty() {
             quote! {
-                <std::vec::Vec<_>>::new()
+                <#alloc::vec::Vec<_>>::new()
             }
         } else {
-            let items = self.iter().map(Transmogrify::transmogrify);
+            let items = self.iter().map(|item| cx.emit(item));
             quote! {
-                <std::vec::Vec<_>>::from([
+                <#alloc::vec::Vec<_> as #core::convert::From<_>>::from([
                     #( #items, )*
                 ])
             }
@@ -37,21 +109,57 @@ impl<T: Transmogrify> Transmogrify for Vec<T> {
     }
 }
 
+/// A slice is emitted as a reference to an array, `&[..]`.
+impl<T: Transmogrify> Transmogrify for [T] {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let items = self.iter().map(|item| cx.emit(item));
+        quote! {
+            &[ #( #items, )* ]
+        }
+    }
+}
+
+impl<T: TransmogrifyConst> TransmogrifyConst for Vec<T> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        self.as_slice().transmogrify_const_with(cx)
+    }
+}
+
+impl<T: TransmogrifyConst> TransmogrifyConst for [T] {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let items = self
+            .iter()
+            .map(|item| item.transmogrify_const_with(cx))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(quote! {
+            &[ #( #items, )* ]
+        })
+    }
+}
+
 impl<K: Transmogrify, V: Transmogrify> Transmogrify for std::collections::BTreeMap<K, V> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
         if self.is_empty() {
             quote! {
-                <std::collections::BTreeMap<_, _>>::new()
+                <#alloc::collections::BTreeMap<_, _>>::new()
             }
         } else {
             let kv = self.iter().map(|(k, v)| {
-                let k = k.transmogrify();
-                let v = v.transmogrify();
+                let k = cx.emit(k);
+                let v = cx.emit(v);
                 quote! { (#k, #v) }
             });
 
             quote! {
-               <std::collections::BTreeMap<_, _>>::from([
+               <#alloc::collections::BTreeMap<_, _> as #core::convert::From<_>>::from([
                     #( #kv, )*
                 ])
             }
@@ -59,16 +167,181 @@ impl<K: Transmogrify, V: Transmogrify> Transmogrify for std::collections::BTreeM
     }
 }
 
+/// Only empty maps have a const representation.
+impl<K, V> TransmogrifyConst for std::collections::BTreeMap<K, V> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            Ok(quote! {
+                <#alloc::collections::BTreeMap<_, _>>::new()
+            })
+        } else {
+            Err(ConstError::new(
+                "a non-empty BTreeMap has no const representation",
+            ))
+        }
+    }
+}
+
 impl<T: Transmogrify> Transmogrify for std::collections::BTreeSet<T> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
         if self.is_empty() {
             quote! {
-               <std::collections::BTreeSet<_, _>>::new()
+               <#alloc::collections::BTreeSet<_>>::new()
             }
         } else {
-            let values = self.iter().map(|value| value.transmogrify());
+            let values = self.iter().map(|value| cx.emit(value));
             quote! {
-               <std::collections::BTreeSet<_, _>>::from([
+               <#alloc::collections::BTreeSet<_> as #core::convert::From<_>>::from([
+                    #( #values, )*
+                ])
+            }
+        }
+    }
+}
+
+/// Only empty sets have a const representation.
+impl<T> TransmogrifyConst for std::collections::BTreeSet<T> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            Ok(quote! {
+                <#alloc::collections::BTreeSet<_>>::new()
+            })
+        } else {
+            Err(ConstError::new(
+                "a non-empty BTreeSet has no const representation",
+            ))
+        }
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for std::collections::VecDeque<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            quote! {
+                <#alloc::collections::VecDeque<_>>::new()
+            }
+        } else {
+            let items = self.iter().map(|item| cx.emit(item));
+            quote! {
+                <#alloc::collections::VecDeque<_> as #core::convert::From<_>>::from([
+                    #( #items, )*
+                ])
+            }
+        }
+    }
+}
+
+/// Items are emitted in ascending order so that output is deterministic.
+impl<T: Transmogrify + Ord> Transmogrify for std::collections::BinaryHeap<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            quote! {
+                <#alloc::collections::BinaryHeap<_>>::new()
+            }
+        } else {
+            let mut items = self.iter().collect::<Vec<_>>();
+            items.sort();
+            let items = items.into_iter().map(|item| cx.emit(item));
+            quote! {
+                <#alloc::collections::BinaryHeap<_> as #core::convert::From<_>>::from([
+                    #( #items, )*
+                ])
+            }
+        }
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for std::collections::LinkedList<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        if self.is_empty() {
+            quote! {
+                <#alloc::collections::LinkedList<_>>::new()
+            }
+        } else {
+            let items = self.iter().map(|item| cx.emit(item));
+            quote! {
+                <#alloc::collections::LinkedList<_> as #core::convert::From<_>>::from([
+                    #( #items, )*
+                ])
+            }
+        }
+    }
+}
+
+/// Entries are sorted by the text of their emitted keys so that output is
+/// deterministic. The output is built with `FromIterator` or `Default` so that
+/// any `BuildHasher` that implements `Default` may be used.
+impl<K, V, S> Transmogrify for std::collections::HashMap<K, V, S>
+where
+    K: Transmogrify,
+    V: Transmogrify,
+{
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        if self.is_empty() {
+            quote! {
+                <#std::collections::HashMap<_, _, _> as #core::default::Default>::default()
+            }
+        } else {
+            let mut kv = self
+                .iter()
+                .map(|(k, v)| (cx.emit(k), cx.emit(v)))
+                .map(|(k, v)| (emitted_text(cx, &k), quote! { (#k, #v) }))
+                .collect::<Vec<_>>();
+            kv.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let kv = kv.into_iter().map(|(_, kv)| kv);
+
+            quote! {
+                <#std::collections::HashMap<_, _, _> as #core::iter::FromIterator<_>>::from_iter([
+                    #( #kv, )*
+                ])
+            }
+        }
+    }
+}
+
+/// Values are sorted by their emitted text; see the impl for `HashMap`.
+impl<T, S> Transmogrify for std::collections::HashSet<T, S>
+where
+    T: Transmogrify,
+{
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        if self.is_empty() {
+            quote! {
+                <#std::collections::HashSet<_, _> as #core::default::Default>::default()
+            }
+        } else {
+            let mut values = self
+                .iter()
+                .map(|value| {
+                    let value = cx.emit(value);
+                    (emitted_text(cx, &value), value)
+                })
+                .collect::<Vec<_>>();
+            values.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let values = values.into_iter().map(|(_, value)| value);
+
+            quote! {
+                <#std::collections::HashSet<_, _> as #core::iter::FromIterator<_>>::from_iter([
                     #( #values, )*
                 ])
             }
@@ -77,59 +350,664 @@ impl<T: Transmogrify> Transmogrify for std::collections::BTreeSet<T> {
 }
 
 impl Transmogrify for String {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        quote! {
+            <#alloc::string::String as #core::convert::From<&str>>::from(#self)
+        }
+    }
+}
+
+/// A `str` is emitted as a string literal, a `&'static str`.
+impl Transmogrify for str {
+    fn transmogrify_with(&self, _cx: &Context) -> proc_macro2::TokenStream {
+        quote! { #self }
+    }
+}
+
+impl TransmogrifyConst for String {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        self.as_str().transmogrify_const_with(cx)
+    }
+}
+
+impl TransmogrifyConst for str {
+    fn transmogrify_const_with(
+        &self,
+        _cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(quote! { #self })
+    }
+}
+
+/// References are emitted as the values they refer to; together with the impls
+/// for `str` and `[T]`, this emits `&str` as a string literal and `&[T]` as
+/// `&[..]`.
+impl<T: Transmogrify + ?Sized> Transmogrify for &T {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        (**self).transmogrify_with(cx)
+    }
+}
+
+impl<T: TransmogrifyConst + ?Sized> TransmogrifyConst for &T {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        (**self).transmogrify_const_with(cx)
+    }
+}
+
+/// A `Cow` is emitted as `Cow::Owned` unless the context specifies
+/// [`Context::with_borrowed_cows`].
+impl<B> Transmogrify for Cow<'_, B>
+where
+    B: Transmogrify + ToOwned + ?Sized,
+    B::Owned: Transmogrify,
+{
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let alloc = cx.alloc_path();
+        if cx.borrowed_cows() {
+            let value = cx.emit(self.as_ref());
+            quote! {
+                #alloc::borrow::Cow::Borrowed(#value)
+            }
+        } else {
+            let value = match self {
+                Cow::Borrowed(b) => cx.emit(&(*b).to_owned()),
+                Cow::Owned(o) => cx.emit(o),
+            };
+            quote! {
+                #alloc::borrow::Cow::Owned(#value)
+            }
+        }
+    }
+}
+
+/// In a const context, a `Cow` is always emitted as `Cow::Borrowed`.
+impl<B> TransmogrifyConst for Cow<'_, B>
+where
+    B: TransmogrifyConst + ToOwned + ?Sized,
+{
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let alloc = cx.alloc_path();
+        let value = self.as_ref().transmogrify_const_with(cx)?;
+        Ok(quote! {
+            #alloc::borrow::Cow::Borrowed(#value)
+        })
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for std::rc::Rc<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let alloc = cx.alloc_path();
+        share(cx, std::rc::Rc::as_ptr(self).cast(), || {
+            let t = cx.emit(self.as_ref());
+            quote! {
+                #alloc::rc::Rc::new(#t)
+            }
+        })
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for std::sync::Arc<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let alloc = cx.alloc_path();
+        share(cx, std::sync::Arc::as_ptr(self).cast(), || {
+            let t = cx.emit(self.as_ref());
+            quote! {
+                #alloc::sync::Arc::new(#t)
+            }
+        })
+    }
+}
+
+impl<T: Transmogrify + Copy> Transmogrify for std::cell::Cell<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let t = cx.emit(&self.get());
         quote! {
-            String::from(#self)
+            #core::cell::Cell::new(#t)
         }
     }
 }
 
+/// A cell that is mutably borrowed has no value to emit; the output is a
+/// `compile_error!` instead.
+impl<T: Transmogrify> Transmogrify for std::cell::RefCell<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        match self.try_borrow() {
+            Ok(value) => {
+                let t = cx.emit(&*value);
+                quote! {
+                    #core::cell::RefCell::new(#t)
+                }
+            }
+            Err(e) => {
+                let message = format!("RefCell could not be transmogrified: {}", e);
+                quote! {
+                    #core::compile_error!(#message)
+                }
+            }
+        }
+    }
+}
+
+impl<T: Transmogrify> Transmogrify for std::cell::OnceCell<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        match self.get() {
+            Some(t) => {
+                let t = cx.emit(t);
+                quote! {
+                    <#core::cell::OnceCell<_> as #core::convert::From<_>>::from(#t)
+                }
+            }
+            None => quote! {
+                #core::cell::OnceCell::new()
+            },
+        }
+    }
+}
+
+/// The value of a poisoned lock is emitted as it is; the output is not
+/// poisoned. A lock that is held elsewhere has no value to emit; the output is
+/// a `compile_error!` instead.
+impl<T: Transmogrify> Transmogrify for std::sync::Mutex<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        let t = match self.try_lock() {
+            Ok(guard) => cx.emit(&*guard),
+            Err(TryLockError::Poisoned(e)) => cx.emit(&*e.into_inner()),
+            Err(e @ TryLockError::WouldBlock) => {
+                let message = format!("Mutex could not be transmogrified: {}", e);
+                return quote! {
+                    #core::compile_error!(#message)
+                };
+            }
+        };
+        quote! {
+            #std::sync::Mutex::new(#t)
+        }
+    }
+}
+
+/// See the impl for `Mutex`.
+impl<T: Transmogrify> Transmogrify for std::sync::RwLock<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        let t = match self.try_read() {
+            Ok(guard) => cx.emit(&*guard),
+            Err(TryLockError::Poisoned(e)) => cx.emit(&*e.into_inner()),
+            Err(e @ TryLockError::WouldBlock) => {
+                let message = format!("RwLock could not be transmogrified: {}", e);
+                return quote! {
+                    #core::compile_error!(#message)
+                };
+            }
+        };
+        quote! {
+            #std::sync::RwLock::new(#t)
+        }
+    }
+}
+
+impl<T: ?Sized> Transmogrify for std::marker::PhantomData<T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        quote! {
+            #core::marker::PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> TransmogrifyConst for std::marker::PhantomData<T> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(self.transmogrify_with(cx))
+    }
+}
+
 macro_rules! quote_impl {
     ($ty:ident) => {
         impl Transmogrify for $ty {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
+            fn transmogrify_with(&self, _cx: &Context) -> proc_macro2::TokenStream {
                 quote! {
                     #self
                 }
             }
         }
+
+        impl TransmogrifyConst for $ty {
+            fn transmogrify_const_with(
+                &self,
+                _cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                Ok(quote! {
+                    #self
+                })
+            }
+        }
     };
 }
 
 quote_impl!(bool);
+quote_impl!(char);
 quote_impl!(i8);
 quote_impl!(i16);
 quote_impl!(i32);
 quote_impl!(i64);
-quote_impl!(u8);
+quote_impl!(i128);
+quote_impl!(isize);
+
+impl Transmogrify for u8 {
+    fn transmogrify_with(&self, _cx: &Context) -> proc_macro2::TokenStream {
+        quote! {
+            #self
+        }
+    }
+
+    fn transmogrify_array_with(items: &[Self], _cx: &Context) -> proc_macro2::TokenStream {
+        let bytes = proc_macro2::Literal::byte_string(items);
+        quote! {
+            *#bytes
+        }
+    }
+}
+
+impl TransmogrifyConst for u8 {
+    fn transmogrify_const_with(
+        &self,
+        _cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(quote! {
+            #self
+        })
+    }
+}
+
 quote_impl!(u16);
 quote_impl!(u32);
 quote_impl!(u64);
-quote_impl!(f32);
-quote_impl!(f64);
+quote_impl!(u128);
+quote_impl!(usize);
 
-macro_rules! non_zero_impl {
-    ($ty:ty, $inner:ident) => {
+// `quote!` panics on non-finite floats so we emit the corresponding
+// constants, or, for NaNs other than the canonical one, the exact bits.
+// Negative values, including -0.0, are emitted as a negated literal; finite
+// values are printed with enough precision to round-trip.
+macro_rules! float_impl {
+    ($ty:ident) => {
         impl Transmogrify for $ty {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let core = cx.core_path();
+                if self.to_bits() == $ty::NAN.to_bits() {
+                    quote! { #core::primitive::$ty::NAN }
+                } else if self.is_nan() {
+                    let bits: proc_macro2::Literal =
+                        format!("{:#x}", self.to_bits()).parse().unwrap();
+                    quote! { #core::primitive::$ty::from_bits(#bits) }
+                } else if *self == $ty::INFINITY {
+                    quote! { #core::primitive::$ty::INFINITY }
+                } else if *self == $ty::NEG_INFINITY {
+                    quote! { #core::primitive::$ty::NEG_INFINITY }
+                } else {
+                    quote! { #self }
+                }
+            }
+        }
+
+        impl TransmogrifyConst for $ty {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                Ok(self.transmogrify_with(cx))
+            }
+        }
+    };
+}
+
+float_impl!(f32);
+float_impl!(f64);
+
+impl<T: Transmogrify, const N: usize> Transmogrify for [T; N] {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        T::transmogrify_array_with(self, cx)
+    }
+}
+
+impl<T: TransmogrifyConst, const N: usize> TransmogrifyConst for [T; N] {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let items = self
+            .iter()
+            .map(|item| item.transmogrify_const_with(cx))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(quote! {
+            [ #( #items, )* ]
+        })
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($ty:ident $var:ident),+) => {
+        impl<$($ty: Transmogrify),+> Transmogrify for ($($ty,)+) {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let ($($var,)+) = self;
+                $( let $var = cx.emit($var); )+
+                quote! {
+                    ( $( #$var, )+ )
+                }
+            }
+        }
+
+        impl<$($ty: TransmogrifyConst),+> TransmogrifyConst for ($($ty,)+) {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                let ($($var,)+) = self;
+                $( let $var = $var.transmogrify_const_with(cx)?; )+
+                Ok(quote! {
+                    ( $( #$var, )+ )
+                })
+            }
+        }
+    };
+}
+
+tuple_impl!(A a);
+tuple_impl!(A a, B b);
+tuple_impl!(A a, B b, C c);
+tuple_impl!(A a, B b, C c, D d);
+tuple_impl!(A a, B b, C c, D d, E e);
+tuple_impl!(A a, B b, C c, D d, E e, F f);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g, H h);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
+tuple_impl!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
+
+impl Transmogrify for () {
+    fn transmogrify_with(&self, _cx: &Context) -> proc_macro2::TokenStream {
+        quote! { () }
+    }
+}
+
+impl TransmogrifyConst for () {
+    fn transmogrify_const_with(
+        &self,
+        _cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(quote! { () })
+    }
+}
+
+macro_rules! non_zero_impl {
+    ($ty:ident, $inner:ident) => {
+        impl Transmogrify for std::num::$ty {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let core = cx.core_path();
+                let value = self.get();
+                quote! {
+                    #core::num::$ty::new(#value).unwrap()
+                }
+            }
+        }
+
+        impl TransmogrifyConst for std::num::$ty {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                let core = cx.core_path();
                 let value = self.get();
+                Ok(quote! {
+                    const {
+                        match #core::num::$ty::new(#value) {
+                            #core::option::Option::Some(value) => value,
+                            #core::option::Option::None => #core::panic!(),
+                        }
+                    }
+                })
+            }
+        }
+    };
+}
+
+non_zero_impl!(NonZeroU8, u8);
+non_zero_impl!(NonZeroU16, u16);
+non_zero_impl!(NonZeroU32, u32);
+non_zero_impl!(NonZeroU64, u64);
+non_zero_impl!(NonZeroU128, u128);
+non_zero_impl!(NonZeroUsize, usize);
+non_zero_impl!(NonZeroI8, i8);
+non_zero_impl!(NonZeroI16, i16);
+non_zero_impl!(NonZeroI32, i32);
+non_zero_impl!(NonZeroI64, i64);
+non_zero_impl!(NonZeroI128, i128);
+non_zero_impl!(NonZeroIsize, isize);
+
+impl Transmogrify for std::time::Duration {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let secs = self.as_secs();
+        let nanos = self.subsec_nanos();
+        if nanos == 0 {
+            quote! {
+                #core::time::Duration::from_secs(#secs)
+            }
+        } else {
+            quote! {
+                #core::time::Duration::new(#secs, #nanos)
+            }
+        }
+    }
+}
+
+impl TransmogrifyConst for std::time::Duration {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(self.transmogrify_with(cx))
+    }
+}
+
+/// A `SystemTime` is emitted as an offset from `UNIX_EPOCH`.
+impl Transmogrify for std::time::SystemTime {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        match self.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since) => {
+                let since = since.transmogrify_with(cx);
+                quote! {
+                    <#std::time::SystemTime as #core::ops::Add<#core::time::Duration>>::add(
+                        #std::time::SystemTime::UNIX_EPOCH,
+                        #since,
+                    )
+                }
+            }
+            Err(e) => {
+                let before = e.duration().transmogrify_with(cx);
                 quote! {
-                    $ty::new(#value).unwrap()
+                    <#std::time::SystemTime as #core::ops::Sub<#core::time::Duration>>::sub(
+                        #std::time::SystemTime::UNIX_EPOCH,
+                        #before,
+                    )
                 }
             }
         }
+    }
+}
+
+impl Transmogrify for std::net::Ipv4Addr {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let [a, b, c, d] = self.octets();
+        quote! {
+            #core::net::Ipv4Addr::new(#a, #b, #c, #d)
+        }
+    }
+}
+
+impl Transmogrify for std::net::Ipv6Addr {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let segments = self.segments();
+        quote! {
+            #core::net::Ipv6Addr::new(#( #segments ),*)
+        }
+    }
+}
+
+impl Transmogrify for std::net::IpAddr {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        match self {
+            std::net::IpAddr::V4(addr) => {
+                let addr = cx.emit(addr);
+                quote! { #core::net::IpAddr::V4(#addr) }
+            }
+            std::net::IpAddr::V6(addr) => {
+                let addr = cx.emit(addr);
+                quote! { #core::net::IpAddr::V6(#addr) }
+            }
+        }
+    }
+}
+
+impl Transmogrify for std::net::SocketAddrV4 {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let ip = cx.emit(self.ip());
+        let port = self.port();
+        quote! {
+            #core::net::SocketAddrV4::new(#ip, #port)
+        }
+    }
+}
+
+impl Transmogrify for std::net::SocketAddrV6 {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let ip = cx.emit(self.ip());
+        let port = self.port();
+        let flowinfo = self.flowinfo();
+        let scope_id = self.scope_id();
+        quote! {
+            #core::net::SocketAddrV6::new(#ip, #port, #flowinfo, #scope_id)
+        }
+    }
+}
+
+impl Transmogrify for std::net::SocketAddr {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        match self {
+            std::net::SocketAddr::V4(addr) => {
+                let addr = cx.emit(addr);
+                quote! { #core::net::SocketAddr::V4(#addr) }
+            }
+            std::net::SocketAddr::V6(addr) => {
+                let addr = cx.emit(addr);
+                quote! { #core::net::SocketAddr::V6(#addr) }
+            }
+        }
+    }
+}
+
+// Addresses are emitted with `const fn` constructors so the same output
+// serves in a const context.
+macro_rules! const_net_impl {
+    ($ty:ident) => {
+        impl TransmogrifyConst for std::net::$ty {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                Ok(self.transmogrify_with(cx))
+            }
+        }
     };
 }
 
-non_zero_impl!(std::num::NonZeroU8, u8);
-non_zero_impl!(std::num::NonZeroU16, u16);
-non_zero_impl!(std::num::NonZeroU32, u32);
-non_zero_impl!(std::num::NonZeroU64, u64);
-non_zero_impl!(std::num::NonZeroU128, u128);
-non_zero_impl!(std::num::NonZeroUsize, usize);
-non_zero_impl!(std::num::NonZeroI8, i8);
-non_zero_impl!(std::num::NonZeroI16, i16);
-non_zero_impl!(std::num::NonZeroI32, i32);
-non_zero_impl!(std::num::NonZeroI64, i64);
-non_zero_impl!(std::num::NonZeroI128, i128);
-non_zero_impl!(std::num::NonZeroIsize, isize);
+const_net_impl!(Ipv4Addr);
+const_net_impl!(Ipv6Addr);
+const_net_impl!(IpAddr);
+const_net_impl!(SocketAddrV4);
+const_net_impl!(SocketAddrV6);
+const_net_impl!(SocketAddr);
+
+/// An `OsString` that is valid UTF-8 is emitted from a string literal. Others
+/// are emitted from their bytes on Unix, making the output Unix-specific, and
+/// converted lossily elsewhere.
+impl Transmogrify for std::ffi::OsString {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        match self.to_str() {
+            Some(s) => quote! {
+                <#std::ffi::OsString as #core::convert::From<&str>>::from(#s)
+            },
+            None => non_utf8_os_string(self, cx),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn non_utf8_os_string(s: &std::ffi::OsStr, cx: &Context) -> proc_macro2::TokenStream {
+    use std::os::unix::ffi::OsStrExt;
+
+    let std = cx.crate_path("std");
+    let bytes = s.as_bytes().to_vec().transmogrify_with(cx);
+    quote! {
+        <#std::ffi::OsString as #std::os::unix::ffi::OsStringExt>::from_vec(#bytes)
+    }
+}
+
+#[cfg(not(unix))]
+fn non_utf8_os_string(s: &std::ffi::OsStr, cx: &Context) -> proc_macro2::TokenStream {
+    let core = cx.core_path();
+    let std = cx.crate_path("std");
+    let s = s.to_string_lossy();
+    quote! {
+        <#std::ffi::OsString as #core::convert::From<&str>>::from(#s)
+    }
+}
+
+/// See the impl for `OsString`.
+impl Transmogrify for std::path::PathBuf {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let std = cx.crate_path("std");
+        match self.to_str() {
+            Some(s) => quote! {
+                <#std::path::PathBuf as #core::convert::From<&str>>::from(#s)
+            },
+            None => {
+                let s = non_utf8_os_string(self.as_os_str(), cx);
+                quote! {
+                    <#std::path::PathBuf as #core::convert::From<#std::ffi::OsString>>::from(#s)
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,238 @@
-use crate::Transmogrify;
+use crate::{ConstError, Context, Transmogrify, TransmogrifyConst};
 
-use quote::quote;
+use chrono::{Datelike, Offset, Timelike};
+use quote::{format_ident, quote};
+
+/// Unwrap, in a const context, the `Option` returned by one of chrono's
+/// `const fn` constructors.
+fn unwrap_const(cx: &Context, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let core = cx.core_path();
+    quote! {
+        const {
+            match #value {
+                #core::option::Option::Some(value) => value,
+                #core::option::Option::None => #core::panic!(),
+            }
+        }
+    }
+}
 
 impl Transmogrify for chrono::DateTime<chrono::Utc> {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let chrono = cx.crate_path("chrono");
         let secs = self.timestamp();
         let nanos = self.timestamp_subsec_nanos();
         quote! {
-            chrono::DateTime::<chrono::Utc>::from_timestamp(#secs, #nanos).unwrap()
+            #chrono::DateTime::<#chrono::Utc>::from_timestamp(#secs, #nanos).unwrap()
+        }
+    }
+}
+
+impl TransmogrifyConst for chrono::DateTime<chrono::Utc> {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let chrono = cx.crate_path("chrono");
+        let secs = self.timestamp();
+        let nanos = self.timestamp_subsec_nanos();
+        Ok(unwrap_const(
+            cx,
+            quote! {
+                #chrono::DateTime::<#chrono::Utc>::from_timestamp(#secs, #nanos)
+            },
+        ))
+    }
+}
+
+// A `DateTime` with an offset is emitted as its UTC time and the offset in
+// effect; for `Local`, this is a fixed instant with the offset at the time of
+// emission rather than that of wherever the output runs.
+macro_rules! offset_date_time_impl {
+    ($tz:ident) => {
+        impl Transmogrify for chrono::DateTime<chrono::$tz> {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let chrono = cx.crate_path("chrono");
+                let naive = cx.emit(&self.naive_utc());
+                let offset = cx.emit(&self.offset().fix());
+                quote! {
+                    #chrono::DateTime::<#chrono::$tz>::from_naive_utc_and_offset(
+                        #naive,
+                        #offset,
+                    )
+                }
+            }
+        }
+
+        impl TransmogrifyConst for chrono::DateTime<chrono::$tz> {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                let chrono = cx.crate_path("chrono");
+                let naive = self.naive_utc().transmogrify_const_with(cx)?;
+                let offset = self.offset().fix().transmogrify_const_with(cx)?;
+                Ok(quote! {
+                    #chrono::DateTime::<#chrono::$tz>::from_naive_utc_and_offset(
+                        #naive,
+                        #offset,
+                    )
+                })
+            }
+        }
+    };
+}
+
+offset_date_time_impl!(FixedOffset);
+offset_date_time_impl!(Local);
+
+impl Transmogrify for chrono::NaiveDateTime {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let chrono = cx.crate_path("chrono");
+        let date = cx.emit(&self.date());
+        let time = cx.emit(&self.time());
+        quote! {
+            #chrono::NaiveDateTime::new(#date, #time)
+        }
+    }
+}
+
+impl TransmogrifyConst for chrono::NaiveDateTime {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        let chrono = cx.crate_path("chrono");
+        let date = self.date().transmogrify_const_with(cx)?;
+        let time = self.time().transmogrify_const_with(cx)?;
+        Ok(quote! {
+            #chrono::NaiveDateTime::new(#date, #time)
+        })
+    }
+}
+
+// Types built by a single fallible `const fn` constructor, the call to which
+// is produced by `$constructor`. The runtime form unwraps the result; the
+// const form matches on it.
+macro_rules! fallible_impl {
+    ($ty:ident, $constructor:ident) => {
+        impl Transmogrify for chrono::$ty {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+                let value = $constructor(self, &cx.crate_path("chrono"));
+                quote! { #value.unwrap() }
+            }
         }
+
+        impl TransmogrifyConst for chrono::$ty {
+            fn transmogrify_const_with(
+                &self,
+                cx: &Context,
+            ) -> Result<proc_macro2::TokenStream, ConstError> {
+                let value = $constructor(self, &cx.crate_path("chrono"));
+                Ok(unwrap_const(cx, value))
+            }
+        }
+    };
+}
+
+fallible_impl!(NaiveDate, naive_date);
+fallible_impl!(NaiveTime, naive_time);
+fallible_impl!(TimeDelta, time_delta);
+fallible_impl!(FixedOffset, fixed_offset);
+
+fn naive_date(
+    date: &chrono::NaiveDate,
+    chrono: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let year = date.year();
+    let month = date.month();
+    let day = date.day();
+    quote! { #chrono::NaiveDate::from_ymd_opt(#year, #month, #day) }
+}
+
+/// A leap second is represented by a nanosecond value of at least one
+/// billion, which `from_hms_nano_opt` accepts.
+fn naive_time(
+    time: &chrono::NaiveTime,
+    chrono: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let hour = time.hour();
+    let min = time.minute();
+    let sec = time.second();
+    let nano = time.nanosecond();
+    quote! { #chrono::NaiveTime::from_hms_nano_opt(#hour, #min, #sec, #nano) }
+}
+
+/// `subsec_nanos` is negative for negative deltas whereas `new` expects
+/// nanoseconds to be added to a (more negative) number of seconds.
+fn time_delta(
+    delta: &chrono::TimeDelta,
+    chrono: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (secs, nanos) = match delta.subsec_nanos() {
+        nanos if nanos < 0 => (delta.num_seconds() - 1, (nanos + 1_000_000_000) as u32),
+        nanos => (delta.num_seconds(), nanos as u32),
+    };
+    quote! { #chrono::TimeDelta::new(#secs, #nanos) }
+}
+
+fn fixed_offset(
+    offset: &chrono::FixedOffset,
+    chrono: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let secs = offset.local_minus_utc();
+    quote! { #chrono::FixedOffset::east_opt(#secs) }
+}
+
+impl Transmogrify for chrono::Weekday {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        const VARIANTS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        let chrono = cx.crate_path("chrono");
+        let variant = format_ident!("{}", VARIANTS[self.num_days_from_monday() as usize]);
+        quote! {
+            #chrono::Weekday::#variant
+        }
+    }
+}
+
+impl TransmogrifyConst for chrono::Weekday {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(self.transmogrify_with(cx))
+    }
+}
+
+impl Transmogrify for chrono::Month {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        const VARIANTS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let chrono = cx.crate_path("chrono");
+        let variant = format_ident!("{}", VARIANTS[self.number_from_month() as usize - 1]);
+        quote! {
+            #chrono::Month::#variant
+        }
+    }
+}
+
+impl TransmogrifyConst for chrono::Month {
+    fn transmogrify_const_with(
+        &self,
+        cx: &Context,
+    ) -> Result<proc_macro2::TokenStream, ConstError> {
+        Ok(self.transmogrify_with(cx))
     }
 }
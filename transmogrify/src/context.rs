@@ -0,0 +1,184 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    dag::{intern, DagNodes},
+    shared::SharedPointers,
+    Transmogrify,
+};
+
+/// Settings that govern the output of [`Transmogrify`](crate::Transmogrify).
+///
+/// By default, emitted code refers to crates by their usual names with a
+/// leading `::` e.g. `::chrono::DateTime` so that it is unaffected by items
+/// in scope where it's used. Consumers of the output may have renamed a crate
+/// in their Cargo.toml or may reach it via a re-export; a `Context` maps crate
+/// names to the paths that should be emitted in their place.
+///
+/// ```
+/// use transmogrify::{Context, Transmogrify};
+///
+/// let cx = Context::new().with_crate_path("std", quote::quote! { ::my_std });
+/// let tokens = vec![1u8].transmogrify_with(&cx);
+/// assert_eq!(
+///     tokens.to_string(),
+///     quote::quote! {
+///         <::my_std::vec::Vec<_> as ::my_std::convert::From<_>>::from([1u8,])
+///     }
+///     .to_string(),
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    crate_paths: BTreeMap<String, TokenStream>,
+    crate_root: Option<TokenStream>,
+    no_std: bool,
+    json_threshold: Option<usize>,
+    borrowed_cows: bool,
+    time_macros: bool,
+    shared_pointers: Option<Rc<RefCell<SharedPointers>>>,
+    dag_nodes: Option<Rc<RefCell<DagNodes>>>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit `path` in place of references to the crate `name`.
+    pub fn with_crate_path(mut self, name: impl Into<String>, path: TokenStream) -> Self {
+        self.crate_paths.insert(name.into(), path);
+        self
+    }
+
+    /// Emit `root` as the path prefix for types that derive `Transmogrify`
+    /// with `#[transmogrify(prefix = $crate)]`.
+    pub fn with_crate_root(mut self, root: TokenStream) -> Self {
+        self.crate_root = Some(root);
+        self
+    }
+
+    /// Emit code for `#![no_std]` crates: items from the standard library are
+    /// referred to via `::core` and `::alloc` rather than `::std`.
+    pub fn with_no_std(mut self, no_std: bool) -> Self {
+        self.no_std = no_std;
+        self
+    }
+
+    /// Emit each `serde_json::Value` whose serialized JSON exceeds
+    /// `threshold` bytes as a string literal that is parsed at runtime rather
+    /// than as a tree of constructors. This trades a runtime parse for
+    /// substantially less generated code.
+    pub fn with_json_threshold(mut self, threshold: usize) -> Self {
+        self.json_threshold = Some(threshold);
+        self
+    }
+
+    /// The size in bytes above which a `serde_json::Value` is emitted as a
+    /// string to be parsed; see [`Context::with_json_threshold`].
+    pub fn json_threshold(&self) -> Option<usize> {
+        self.json_threshold
+    }
+
+    /// Emit `Cow` values as `Cow::Borrowed` rather than `Cow::Owned` so that
+    /// the output may be `'static` without allocating. This requires that
+    /// the borrowed value be emitted as a `'static` reference, as it is for
+    /// `str` and for slices of literals.
+    pub fn with_borrowed_cows(mut self, borrowed_cows: bool) -> Self {
+        self.borrowed_cows = borrowed_cows;
+        self
+    }
+
+    /// Whether `Cow` values are emitted as `Cow::Borrowed`; see
+    /// [`Context::with_borrowed_cows`].
+    pub fn borrowed_cows(&self) -> bool {
+        self.borrowed_cows
+    }
+
+    /// Emit values from the `time` crate with its macros, e.g.
+    /// `::time::macros::date!(2020-01-01)`, rather than with calls to
+    /// constructors. This requires that the consumer of the output enable the
+    /// `macros` feature of `time`.
+    pub fn with_time_macros(mut self, time_macros: bool) -> Self {
+        self.time_macros = time_macros;
+        self
+    }
+
+    /// Whether values from the `time` crate are emitted with its macros; see
+    /// [`Context::with_time_macros`].
+    pub fn time_macros(&self) -> bool {
+        self.time_macros
+    }
+
+    /// Emit a value contained within the one being transmogrified.
+    /// Implementations of `Transmogrify` should use this rather than calling
+    /// `transmogrify_with` on contained values directly so that
+    /// [`transmogrify_dag`](crate::transmogrify_dag) can find values that
+    /// are repeated.
+    pub fn emit<T: Transmogrify + ?Sized>(&self, value: &T) -> TokenStream {
+        let tokens = value.transmogrify_with(self);
+        intern(self, std::any::type_name::<T>(), tokens)
+    }
+
+    pub(crate) fn with_dag_nodes(mut self, nodes: Rc<RefCell<DagNodes>>) -> Self {
+        self.dag_nodes = Some(nodes);
+        self
+    }
+
+    pub(crate) fn dag_nodes(&self) -> Option<&Rc<RefCell<DagNodes>>> {
+        self.dag_nodes.as_ref()
+    }
+
+    pub(crate) fn with_shared_pointers(mut self, pointers: Rc<RefCell<SharedPointers>>) -> Self {
+        self.shared_pointers = Some(pointers);
+        self
+    }
+
+    pub(crate) fn shared_pointers(&self) -> Option<&Rc<RefCell<SharedPointers>>> {
+        self.shared_pointers.as_ref()
+    }
+
+    /// The path to emit for items in `core` such as `Option` or `From`:
+    /// `::core` for `no_std` output and `::std` otherwise.
+    pub fn core_path(&self) -> TokenStream {
+        if self.no_std {
+            self.crate_path("core")
+        } else {
+            self.crate_path("std")
+        }
+    }
+
+    /// The path to emit for items in `alloc` such as `Vec` or `String`:
+    /// `::alloc` for `no_std` output and `::std` otherwise.
+    pub fn alloc_path(&self) -> TokenStream {
+        if self.no_std {
+            self.crate_path("alloc")
+        } else {
+            self.crate_path("std")
+        }
+    }
+
+    /// The path to emit for references to the crate `name`; `::name` unless
+    /// otherwise specified.
+    pub fn crate_path(&self, name: &str) -> TokenStream {
+        match self.crate_paths.get(name) {
+            Some(path) => path.clone(),
+            None => {
+                let ident = quote::format_ident!("{}", name);
+                quote! { ::#ident }
+            }
+        }
+    }
+
+    /// The path prefix for types that derive `Transmogrify` with
+    /// `#[transmogrify(prefix = $crate)]`; `crate` unless otherwise
+    /// specified.
+    pub fn crate_root(&self) -> TokenStream {
+        match &self.crate_root {
+            Some(root) => root.clone(),
+            None => quote! { crate },
+        }
+    }
+}
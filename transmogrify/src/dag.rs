@@ -0,0 +1,241 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+
+use crate::{shared::expand_shared, Context, Transmogrify};
+
+/// Values whose emitted code is shorter than this are never bound to a
+/// variable; a reference to the variable, `::std::clone::Clone::clone(&__tm_0)`,
+/// would be about as long.
+const MIN_SIZE: usize = 64;
+
+/// Emit code for `value` in which each repeated contained value is emitted
+/// once, bound to a variable, and cloned at each use. The output is a block
+/// expression:
+///
+/// ```text
+/// {
+///     let __tm_0 = ..;
+///     Config {
+///         primary: ::std::clone::Clone::clone(&__tm_0),
+///         fallback: ::std::clone::Clone::clone(&__tm_0),
+///     }
+/// }
+/// ```
+///
+/// Values are considered the same if they have the same type and emit the
+/// same code. This can considerably shrink the output for repetitive data.
+/// The types of repeated values must implement `Clone`.
+///
+/// Only contained values emitted with [`Context::emit`] are considered; this
+/// includes those of all types in this crate and of derived implementations.
+pub fn transmogrify_dag<T: Transmogrify + ?Sized>(value: &T, cx: &Context) -> TokenStream {
+    let nodes = Rc::new(RefCell::new(DagNodes::default()));
+    let cx = cx.clone().with_dag_nodes(nodes.clone());
+    let root = cx.emit(value);
+    let nodes = std::mem::take(&mut *nodes.borrow_mut());
+    nodes.resolve(root, &cx)
+}
+
+/// The distinct values emitted within a [`transmogrify_dag`] emission. Each
+/// is emitted as a placeholder identifier that refers to its entry here.
+#[derive(Debug, Default)]
+pub(crate) struct DagNodes {
+    ids: HashMap<(&'static str, String), usize>,
+    nodes: Vec<DagNode>,
+}
+
+#[derive(Debug)]
+struct DagNode {
+    /// The emitted code, with contained values replaced by placeholders.
+    tokens: TokenStream,
+    /// The ids of the placeholders in `tokens`, with repetition.
+    children: Vec<usize>,
+    /// The length of the code with all placeholders expanded, ignoring
+    /// whitespace.
+    size: usize,
+}
+
+/// Record the code emitted for a value of type `type_name`; within
+/// [`transmogrify_dag`], return a placeholder for it.
+pub(crate) fn intern(cx: &Context, type_name: &'static str, tokens: TokenStream) -> TokenStream {
+    let Some(nodes) = cx.dag_nodes() else {
+        return tokens;
+    };
+    let mut nodes = nodes.borrow_mut();
+    let key = (type_name, tokens.to_string());
+    let id = match nodes.ids.get(&key) {
+        Some(id) => *id,
+        None => {
+            let mut children = Vec::new();
+            let size = nodes.scan(tokens.clone(), &mut children);
+            let id = nodes.nodes.len();
+            nodes.nodes.push(DagNode {
+                tokens,
+                children,
+                size,
+            });
+            nodes.ids.insert(key, id);
+            id
+        }
+    };
+    let placeholder = placeholder(id);
+    quote! { #placeholder }
+}
+
+fn placeholder(id: usize) -> proc_macro2::Ident {
+    format_ident!("__tm_node_{}", id)
+}
+
+fn placeholder_id(ident: &proc_macro2::Ident) -> Option<usize> {
+    ident.to_string().strip_prefix("__tm_node_")?.parse().ok()
+}
+
+/// The text of emitted code with any placeholders expanded, for use where an
+/// implementation orders values by their emitted code.
+pub(crate) fn emitted_text(cx: &Context, tokens: &TokenStream) -> String {
+    let tokens = match cx.dag_nodes() {
+        Some(nodes) => nodes
+            .borrow()
+            .expand(tokens.clone(), &[], &TokenStream::new()),
+        None => tokens.clone(),
+    };
+    expand_shared(cx, tokens).to_string()
+}
+
+impl DagNodes {
+    /// Collect the placeholders in `tokens` and compute its expanded size.
+    /// Literals count by their length so that large strings and byte arrays,
+    /// which are single tokens, are weighed appropriately.
+    fn scan(&self, tokens: TokenStream, children: &mut Vec<usize>) -> usize {
+        tokens
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Ident(ident) => match placeholder_id(&ident) {
+                    Some(id) => {
+                        children.push(id);
+                        self.nodes[id].size
+                    }
+                    None => ident.to_string().len(),
+                },
+                TokenTree::Group(group) => {
+                    let delimiters = match group.delimiter() {
+                        proc_macro2::Delimiter::None => 0,
+                        _ => 2,
+                    };
+                    delimiters + self.scan(group.stream(), children)
+                }
+                TokenTree::Literal(literal) => literal.to_string().len(),
+                TokenTree::Punct(_) => 1,
+            })
+            .sum()
+    }
+
+    /// Produce the final code for the value whose placeholder is `root`.
+    fn resolve(self, root: TokenStream, cx: &Context) -> TokenStream {
+        let Some(TokenTree::Ident(ident)) = root.into_iter().next() else {
+            unreachable!()
+        };
+        let root = placeholder_id(&ident).unwrap();
+
+        // Determine how many times each value would appear in the output and
+        // bind those that appear more than once. A node's id is greater than
+        // those of the nodes it contains so we visit containers first.
+        let mut uses = vec![0usize; self.nodes.len()];
+        let mut bound = vec![false; self.nodes.len()];
+        uses[root] = 1;
+        for id in (0..self.nodes.len()).rev() {
+            let node = &self.nodes[id];
+            bound[id] = id != root && uses[id] > 1 && node.size >= MIN_SIZE;
+            let count = if bound[id] { 1 } else { uses[id] };
+            for child in &node.children {
+                uses[*child] = uses[*child].saturating_add(count);
+            }
+        }
+
+        // Number bindings in the order they're first needed in the output
+        // so that it doesn't depend on the order in which values were
+        // emitted. Each binding follows those of the values it contains.
+        let mut names = vec![None; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.nodes.len()];
+        self.order(root, &bound, &mut visited, &mut names, &mut order);
+
+        let core = cx.core_path();
+        let bindings = order
+            .into_iter()
+            .map(|id| {
+                let name = &names[id];
+                let value = self.expand(self.nodes[id].tokens.clone(), &names, &core);
+                quote! {
+                    let #name = #value;
+                }
+            })
+            .collect::<Vec<_>>();
+        let value = self.expand(self.nodes[root].tokens.clone(), &names, &core);
+
+        if bindings.is_empty() {
+            value
+        } else {
+            quote! {
+                {
+                    #( #bindings )*
+                    #value
+                }
+            }
+        }
+    }
+
+    /// Name the bound values contained in `id` in post-order.
+    fn order(
+        &self,
+        id: usize,
+        bound: &[bool],
+        visited: &mut [bool],
+        names: &mut [Option<proc_macro2::Ident>],
+        order: &mut Vec<usize>,
+    ) {
+        if std::mem::replace(&mut visited[id], true) {
+            return;
+        }
+        for child in &self.nodes[id].children {
+            self.order(*child, bound, visited, names, order);
+        }
+        if bound[id] {
+            names[id] = Some(format_ident!("__tm_{}", order.len()));
+            order.push(id);
+        }
+    }
+
+    /// Replace placeholders in `tokens` with references to bound variables or
+    /// with the code they represent.
+    fn expand(
+        &self,
+        tokens: TokenStream,
+        names: &[Option<proc_macro2::Ident>],
+        core: &TokenStream,
+    ) -> TokenStream {
+        tokens
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Ident(ident) => match placeholder_id(&ident) {
+                    Some(id) => match names.get(id).and_then(Option::as_ref) {
+                        Some(name) => quote! { #core::clone::Clone::clone(&#name) },
+                        None => self.expand(self.nodes[id].tokens.clone(), names, core),
+                    },
+                    None => TokenTree::Ident(ident).into(),
+                },
+                TokenTree::Group(group) => {
+                    let mut expanded = proc_macro2::Group::new(
+                        group.delimiter(),
+                        self.expand(group.stream(), names, core),
+                    );
+                    expanded.set_span(group.span());
+                    TokenTree::Group(expanded).into()
+                }
+                tt => tt.into(),
+            })
+            .collect()
+    }
+}
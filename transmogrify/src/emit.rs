@@ -0,0 +1,71 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::{Context, Transmogrify};
+
+/// Emit a `static` item named `name` of type `ty` whose value is computed on
+/// first use:
+///
+/// ```text
+/// static NAME: ::std::sync::LazyLock<ty> = ::std::sync::LazyLock::new(|| value);
+/// ```
+///
+/// The item is private; prepend a visibility such as `pub` if needed.
+/// `LazyLock` requires Rust 1.80; see [`emit_static_fn`] for older compilers.
+pub fn emit_static<T: Transmogrify + ?Sized>(
+    name: &Ident,
+    ty: TokenStream,
+    value: &T,
+) -> TokenStream {
+    emit_static_with(name, ty, value, &Context::default())
+}
+
+/// Like [`emit_static`], with paths determined by the given [`Context`].
+pub fn emit_static_with<T: Transmogrify + ?Sized>(
+    name: &Ident,
+    ty: TokenStream,
+    value: &T,
+    cx: &Context,
+) -> TokenStream {
+    let std = cx.crate_path("std");
+    let value = value.transmogrify_with(cx);
+    quote! {
+        static #name: #std::sync::LazyLock<#ty> = #std::sync::LazyLock::new(|| #value);
+    }
+}
+
+/// Emit a function named `name` that returns a `&'static` reference to a
+/// value of type `ty`, computed on first use and stored in a `OnceLock`:
+///
+/// ```text
+/// fn name() -> &'static ty {
+///     static VALUE: ::std::sync::OnceLock<ty> = ::std::sync::OnceLock::new();
+///     VALUE.get_or_init(|| value)
+/// }
+/// ```
+///
+/// The function is private; prepend a visibility such as `pub` if needed.
+pub fn emit_static_fn<T: Transmogrify + ?Sized>(
+    name: &Ident,
+    ty: TokenStream,
+    value: &T,
+) -> TokenStream {
+    emit_static_fn_with(name, ty, value, &Context::default())
+}
+
+/// Like [`emit_static_fn`], with paths determined by the given [`Context`].
+pub fn emit_static_fn_with<T: Transmogrify + ?Sized>(
+    name: &Ident,
+    ty: TokenStream,
+    value: &T,
+    cx: &Context,
+) -> TokenStream {
+    let std = cx.crate_path("std");
+    let value = value.transmogrify_with(cx);
+    quote! {
+        fn #name() -> &'static #ty {
+            static VALUE: #std::sync::OnceLock<#ty> = #std::sync::OnceLock::new();
+            VALUE.get_or_init(|| #value)
+        }
+    }
+}
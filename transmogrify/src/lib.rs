@@ -1,24 +1,103 @@
 #![doc = include_str!("../../README.md")]
 
-pub use transmogrify_derive::Transmogrify;
+pub use transmogrify_derive::{Transmogrify, TransmogrifyConst};
 
 mod basic;
 #[cfg(feature = "chrono")]
 mod chrono;
+mod context;
+mod dag;
+mod emit;
 #[cfg(feature = "schemars")]
 mod schemars;
-#[cfg(any(feature = "schemars", feature = "json_value"))]
+#[cfg(any(feature = "schemars", feature = "json-value"))]
 mod serde_json_value;
+mod shared;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "serde")]
+mod via_serde;
 
+pub use context::Context;
+pub use dag::transmogrify_dag;
+pub use emit::{emit_static, emit_static_fn, emit_static_fn_with, emit_static_with};
+pub use shared::transmogrify_shared;
+#[cfg(feature = "serde")]
+pub use via_serde::{via_serde, ViaSerde};
+
+/// Implementations provide `transmogrify_with`; `transmogrify` calls it with
+/// the default [`Context`].
 pub trait Transmogrify {
-    fn transmogrify(&self) -> proc_macro2::TokenStream;
+    /// Emit code that produces this value using the default [`Context`].
+    fn transmogrify(&self) -> proc_macro2::TokenStream {
+        self.transmogrify_with(&Context::default())
+    }
+
+    /// Emit code that produces this value, with paths determined by the
+    /// given [`Context`]. Implementations should pass the context along when
+    /// transmogrifying contained values.
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream;
+
+    /// Emit code that produces an array of these values. This permits a
+    /// more compact form for some element types, e.g. `*b"..."` for bytes.
+    #[doc(hidden)]
+    fn transmogrify_array_with(items: &[Self], cx: &Context) -> proc_macro2::TokenStream
+    where
+        Self: Sized,
+    {
+        let items = items.iter().map(|item| cx.emit(item));
+        quote::quote! {
+            [ #( #items, )* ]
+        }
+    }
+}
+
+/// Like [`Transmogrify`], but emits code that may be evaluated in a const
+/// context such as the initializer of a `const` or `static` item.
+///
+/// Owned types are emitted in their borrowed forms: a `String` becomes a
+/// `&'static str`, a `Vec<T>` becomes a `&'static [T]`, and a `Box<T>`
+/// becomes a `&'static T`. Values that have no const representation produce
+/// a [`ConstError`].
+pub trait TransmogrifyConst {
+    /// Emit a const expression for this value using the default [`Context`].
+    fn transmogrify_const(&self) -> Result<proc_macro2::TokenStream, ConstError> {
+        self.transmogrify_const_with(&Context::default())
+    }
+
+    /// Emit a const expression for this value, with paths determined by the
+    /// given [`Context`].
+    fn transmogrify_const_with(&self, cx: &Context)
+        -> Result<proc_macro2::TokenStream, ConstError>;
 }
 
+/// The error produced when a value has no const representation.
+#[derive(Clone, Debug)]
+pub struct ConstError {
+    message: String,
+}
+
+impl ConstError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConstError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ConstError {}
+
 #[cfg(test)]
 mod tests {
     use transmogrify_macro::transmogrify;
 
-    use crate::Transmogrify;
+    use crate::{Context, Transmogrify};
 
     #[test]
     fn test_generated_transmogrify() {
@@ -38,7 +117,7 @@ mod tests {
 
         #[transmogrify]
         impl Transmogrify for TestEnum {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                 match self {
                     TestEnum::A => todo!(),
                     TestEnum::B(_, _) => todo!(),
@@ -49,7 +128,7 @@ mod tests {
 
         #[transmogrify]
         impl Transmogrify for TestStruct {
-            fn transmogrify(&self) -> proc_macro2::TokenStream {
+            fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
                 let Self { a, b } = self;
                 todo!()
             }
@@ -63,7 +142,10 @@ mod tests {
 
         let value = TestEnum::B(32, "s".to_string()).transmogrify();
         let expected = quote::quote! {
-            TestEnum::B(32u32, String::from("s"),)
+            TestEnum::B(
+                32u32,
+                <::std::string::String as ::std::convert::From<&str>>::from("s"),
+            )
         };
         assert_eq!(value.to_string(), expected.to_string());
 
@@ -81,7 +163,7 @@ mod tests {
         let expected = quote::quote! {
             TestStruct {
                 a: 100u32,
-                b: String::from("b"),
+                b: <::std::string::String as ::std::convert::From<&str>>::from("b"),
             }
         };
         assert_eq!(value.to_string(), expected.to_string());
@@ -1,10 +1,10 @@
 use transmogrify_macro::transmogrify;
 
-use crate::Transmogrify;
+use crate::{Context, Transmogrify};
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::Schema {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             schemars::schema::Schema::Bool(_) => todo!(),
             schemars::schema::Schema::Object(_) => todo!(),
@@ -14,7 +14,7 @@ impl Transmogrify for schemars::schema::Schema {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::SchemaObject {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             schemars::schema::SchemaObject {
                 metadata,
@@ -39,7 +39,7 @@ impl<T> Transmogrify for schemars::schema::SingleOrVec<T>
 where
     T: Transmogrify,
 {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             schemars::schema::SingleOrVec::Single(_) => todo!(),
             schemars::schema::SingleOrVec::Vec(_) => todo!(),
@@ -49,7 +49,7 @@ where
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::Metadata {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             Self {
                 id,
@@ -67,7 +67,7 @@ impl Transmogrify for schemars::schema::Metadata {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::InstanceType {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             schemars::schema::InstanceType::Null => todo!(),
             schemars::schema::InstanceType::Boolean => todo!(),
@@ -82,7 +82,7 @@ impl Transmogrify for schemars::schema::InstanceType {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::SubschemaValidation {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         let schemars::schema::SubschemaValidation {
             all_of,
             any_of,
@@ -97,7 +97,7 @@ impl Transmogrify for schemars::schema::SubschemaValidation {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::NumberValidation {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             Self {
                 multiple_of,
@@ -112,7 +112,7 @@ impl Transmogrify for schemars::schema::NumberValidation {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::StringValidation {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             Self {
                 max_length,
@@ -125,7 +125,7 @@ impl Transmogrify for schemars::schema::StringValidation {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::ArrayValidation {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             Self {
                 items,
@@ -141,7 +141,7 @@ impl Transmogrify for schemars::schema::ArrayValidation {
 
 #[transmogrify]
 impl Transmogrify for schemars::schema::ObjectValidation {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
         match self {
             Self {
                 max_properties,
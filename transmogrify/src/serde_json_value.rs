@@ -1,33 +1,105 @@
-use transmogrify_macro::transmogrify;
+use quote::quote;
 
-use crate::Transmogrify;
+use crate::{Context, Transmogrify};
 
-#[transmogrify]
 impl Transmogrify for serde_json::Value {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let serde_json = cx.crate_path("serde_json");
+        if let Some(threshold) = cx.json_threshold() {
+            let json = self.to_string();
+            if json.len() > threshold {
+                let json = raw_string(&json);
+                return quote! {
+                    #serde_json::from_str::<#serde_json::Value>(#json).unwrap()
+                };
+            }
+        }
         match self {
-            serde_json::Value::Null => todo!(),
-            serde_json::Value::Bool(_) => todo!(),
-            serde_json::Value::Number(_) => todo!(),
-            serde_json::Value::String(_) => todo!(),
-            serde_json::Value::Array(_) => todo!(),
-            serde_json::Value::Object(_) => todo!(),
+            serde_json::Value::Null => quote! { #serde_json::Value::Null },
+            serde_json::Value::Bool(b) => quote! { #serde_json::Value::Bool(#b) },
+            serde_json::Value::Number(n) => {
+                let n = cx.emit(n);
+                quote! { #serde_json::Value::Number(#n) }
+            }
+            serde_json::Value::String(s) => {
+                let s = cx.emit(s);
+                quote! { #serde_json::Value::String(#s) }
+            }
+            serde_json::Value::Array(a) => {
+                let a = cx.emit(a);
+                quote! { #serde_json::Value::Array(#a) }
+            }
+            serde_json::Value::Object(o) => {
+                let o = cx.emit(o);
+                quote! { #serde_json::Value::Object(#o) }
+            }
         }
     }
 }
 
+/// A raw string literal, `r#"..."#`, with enough `#`s to contain `s`.
+pub(crate) fn raw_string(s: &str) -> proc_macro2::Literal {
+    let mut hashes = 1;
+    while s.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    format!("r{hashes}\"{s}\"{hashes}").parse().unwrap()
+}
+
+/// Numbers are emitted as the `u64`, `i64`, or `f64` they hold. With
+/// serde_json's `arbitrary_precision` feature, a number may hold more digits
+/// than any of those represent; it is emitted as a string to be parsed.
 impl Transmogrify for serde_json::Number {
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        todo!()
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let serde_json = cx.crate_path("serde_json");
+        let repr = self.to_string();
+        if let Some(n) = self.as_u64().filter(|n| n.to_string() == repr) {
+            quote! {
+                <#serde_json::Number as #core::convert::From<u64>>::from(#n)
+            }
+        } else if let Some(n) = self.as_i64().filter(|n| n.to_string() == repr) {
+            quote! {
+                <#serde_json::Number as #core::convert::From<i64>>::from(#n)
+            }
+        } else if let Some(n) = self.as_f64().filter(|n| {
+            serde_json::Number::from_f64(*n).map(|n| n.to_string()) == Some(repr.clone())
+        }) {
+            quote! {
+                #serde_json::Number::from_f64(#n).unwrap()
+            }
+        } else {
+            quote! {
+                #serde_json::from_str::<#serde_json::Number>(#repr).unwrap()
+            }
+        }
     }
 }
 
-impl<K, V> Transmogrify for serde_json::Map<K, V>
-where
-    K: Transmogrify,
-    V: Transmogrify,
-{
-    fn transmogrify(&self) -> proc_macro2::TokenStream {
-        todo!()
+/// Entries are emitted in iteration order, so insertion order is preserved
+/// when serde_json's `preserve_order` feature is enabled.
+impl Transmogrify for serde_json::Map<String, serde_json::Value> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let core = cx.core_path();
+        let alloc = cx.alloc_path();
+        let serde_json = cx.crate_path("serde_json");
+        if self.is_empty() {
+            quote! {
+                #serde_json::Map::new()
+            }
+        } else {
+            let kv = self.iter().map(|(k, v)| {
+                let k = cx.emit(k);
+                let v = cx.emit(v);
+                quote! { (#k, #v) }
+            });
+            quote! {
+                <#serde_json::Map<#alloc::string::String, #serde_json::Value>
+                    as #core::iter::FromIterator<_>>::from_iter([
+                    #( #kv, )*
+                ])
+            }
+        }
     }
 }
@@ -0,0 +1,212 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+
+use crate::{Context, Transmogrify};
+
+/// Emit code for `value` in which each `Rc` or `Arc` that is reached more than
+/// once--i.e. whose allocation is shared within the value--is constructed
+/// once, bound to a variable, and cloned at each use. The output is a block
+/// expression:
+///
+/// ```text
+/// {
+///     let __tm_ptr_0 = ::std::rc::Rc::new(..);
+///     Node {
+///         left: ::std::clone::Clone::clone(&__tm_ptr_0),
+///         right: ::std::clone::Clone::clone(&__tm_ptr_0),
+///     }
+/// }
+/// ```
+///
+/// Without this, shared values are emitted once per reference, and the
+/// resulting value no longer shares them.
+pub fn transmogrify_shared<T: Transmogrify + ?Sized>(value: &T, cx: &Context) -> TokenStream {
+    let pointers = Rc::new(RefCell::new(SharedPointers {
+        counting: true,
+        ..Default::default()
+    }));
+    let cx = cx.clone().with_shared_pointers(pointers.clone());
+
+    // The first pass counts references to each pointer; the second emits code.
+    let _ = value.transmogrify_with(&cx);
+    pointers.borrow_mut().counting = false;
+    let expr = value.transmogrify_with(&cx);
+
+    let pointers = std::mem::take(&mut *pointers.borrow_mut());
+    pointers.resolve(expr, &cx)
+}
+
+/// The state of a [`transmogrify_shared`] emission. Each pointer that is
+/// reached more than once is emitted as a placeholder identifier that refers
+/// to its entry in `values`.
+#[derive(Debug, Default)]
+pub(crate) struct SharedPointers {
+    counting: bool,
+    counts: HashMap<*const (), usize>,
+    ids: HashMap<*const (), usize>,
+    values: Vec<TokenStream>,
+}
+
+/// Emit the value at `ptr`, which is owned by a shared pointer, with
+/// `emit`. Within [`transmogrify_shared`], a pointer that is reached more
+/// than once is emitted as a placeholder for a variable bound to its value.
+pub(crate) fn share(
+    cx: &Context,
+    ptr: *const (),
+    emit: impl FnOnce() -> TokenStream,
+) -> TokenStream {
+    let Some(pointers) = cx.shared_pointers() else {
+        return emit();
+    };
+
+    if pointers.borrow().counting {
+        let count = {
+            let mut pointers = pointers.borrow_mut();
+            let count = pointers.counts.entry(ptr).or_default();
+            *count += 1;
+            *count
+        };
+        // Visit the contents only once so that DAGs are traversed in
+        // linear time.
+        if count == 1 {
+            let _ = emit();
+        }
+        return TokenStream::new();
+    }
+
+    if pointers
+        .borrow()
+        .counts
+        .get(&ptr)
+        .copied()
+        .unwrap_or_default()
+        < 2
+    {
+        return emit();
+    }
+
+    let existing = pointers.borrow().ids.get(&ptr).copied();
+    let id = match existing {
+        Some(id) => id,
+        None => {
+            let value = emit();
+            let mut pointers = pointers.borrow_mut();
+            let id = pointers.values.len();
+            pointers.values.push(value);
+            pointers.ids.insert(ptr, id);
+            id
+        }
+    };
+    let placeholder = placeholder(id);
+    quote! { #placeholder }
+}
+
+fn placeholder(id: usize) -> proc_macro2::Ident {
+    format_ident!("__tm_shared_{}", id)
+}
+
+fn placeholder_id(ident: &proc_macro2::Ident) -> Option<usize> {
+    ident.to_string().strip_prefix("__tm_shared_")?.parse().ok()
+}
+
+/// Expand the placeholders for shared pointers in `tokens`, for use where an
+/// implementation orders values by their emitted code.
+pub(crate) fn expand_shared(cx: &Context, tokens: TokenStream) -> TokenStream {
+    match cx.shared_pointers() {
+        Some(pointers) => pointers.borrow().expand(tokens, &[], &TokenStream::new()),
+        None => tokens,
+    }
+}
+
+impl SharedPointers {
+    /// Produce the final code for `expr`. Bindings are numbered in the order
+    /// they're first needed in the output so that it doesn't depend on the
+    /// order in which values were emitted, which for a `HashMap` varies.
+    fn resolve(self, expr: TokenStream, cx: &Context) -> TokenStream {
+        let mut names = vec![None; self.values.len()];
+        let mut order = Vec::new();
+        self.order(expr.clone(), &mut names, &mut order);
+
+        let core = cx.core_path();
+        let bindings = order
+            .into_iter()
+            .map(|id| {
+                let name = &names[id];
+                let value = self.expand(self.values[id].clone(), &names, &core);
+                quote! {
+                    let #name = #value;
+                }
+            })
+            .collect::<Vec<_>>();
+        let expr = self.expand(expr, &names, &core);
+
+        if bindings.is_empty() {
+            expr
+        } else {
+            quote! {
+                {
+                    #( #bindings )*
+                    #expr
+                }
+            }
+        }
+    }
+
+    /// Name the pointers referred to in `tokens`. Each is named after those
+    /// its value refers to, as it must be bound after them.
+    fn order(
+        &self,
+        tokens: TokenStream,
+        names: &mut [Option<proc_macro2::Ident>],
+        order: &mut Vec<usize>,
+    ) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Ident(ident) => {
+                    if let Some(id) = placeholder_id(&ident) {
+                        if names[id].is_none() {
+                            self.order(self.values[id].clone(), names, order);
+                            names[id] = Some(format_ident!("__tm_ptr_{}", order.len()));
+                            order.push(id);
+                        }
+                    }
+                }
+                TokenTree::Group(group) => self.order(group.stream(), names, order),
+                _ => {}
+            }
+        }
+    }
+
+    /// Replace placeholders in `tokens` with clones of the named variables
+    /// or, where a pointer isn't named, with the code for its value.
+    fn expand(
+        &self,
+        tokens: TokenStream,
+        names: &[Option<proc_macro2::Ident>],
+        core: &TokenStream,
+    ) -> TokenStream {
+        tokens
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Ident(ident) => match placeholder_id(&ident) {
+                    Some(id) => match names.get(id).and_then(Option::as_ref) {
+                        Some(name) => quote! { #core::clone::Clone::clone(&#name) },
+                        None => self.expand(self.values[id].clone(), names, core),
+                    },
+                    None => TokenTree::Ident(ident).into(),
+                },
+                TokenTree::Group(group) => {
+                    let mut expanded = proc_macro2::Group::new(
+                        group.delimiter(),
+                        self.expand(group.stream(), names, core),
+                    );
+                    expanded.set_span(group.span());
+                    TokenTree::Group(expanded).into()
+                }
+                tt => tt.into(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{ConstError, Context, Transmogrify, TransmogrifyConst};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Unwrap, in a const context, the `Result` returned by one of the `time`
+/// crate's `const fn` constructors.
+fn unwrap_const(cx: &Context, value: TokenStream) -> TokenStream {
+    let core = cx.core_path();
+    quote! {
+        const {
+            match #value {
+                #core::result::Result::Ok(value) => value,
+                #core::result::Result::Err(_) => #core::panic!(),
+            }
+        }
+    }
+}
+
+/// Invoke one of the `time::macros` macros with `input`, which is assembled as
+/// text in the format the macro expects.
+fn time_macro(cx: &Context, name: &str, input: String) -> TokenStream {
+    let time = cx.crate_path("time");
+    let name = format_ident!("{}", name);
+    let input: TokenStream = input.parse().unwrap();
+    quote! {
+        #time::macros::#name!(#input)
+    }
+}
+
+fn date_text(date: &time::Date) -> String {
+    let year = match date.year() {
+        year @ 0..=9999 => format!("{:04}", year),
+        year @ 10000.. => format!("+{}", year),
+        year => format!("{:05}", year),
+    };
+    format!("{}-{:02}-{:02}", year, u8::from(date.month()), date.day())
+}
+
+fn time_text(time: &time::Time) -> String {
+    let (hour, minute, second, nano) = time.as_hms_nano();
+    if nano == 0 {
+        format!("{}:{:02}:{:02}", hour, minute, second)
+    } else {
+        format!("{}:{:02}:{:02}.{:09}", hour, minute, second, nano)
+    }
+}
+
+fn offset_text(offset: &time::UtcOffset) -> String {
+    let (hours, minutes, seconds) = offset.as_hms();
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    format!(
+        "{}{:02}:{:02}:{:02}",
+        sign,
+        hours.unsigned_abs(),
+        minutes.unsigned_abs(),
+        seconds.unsigned_abs(),
+    )
+}
+
+// Types built by a single fallible `const fn` constructor, the call to which
+// is produced by `$constructor`, or by a macro when the context specifies
+// `Context::with_time_macros`. The runtime form unwraps the result; the const
+// form matches on it.
+macro_rules! fallible_impl {
+    ($ty:ident, $constructor:ident, $macro:literal, $text:ident) => {
+        impl Transmogrify for time::$ty {
+            fn transmogrify_with(&self, cx: &Context) -> TokenStream {
+                if cx.time_macros() {
+                    time_macro(cx, $macro, $text(self))
+                } else {
+                    let value = $constructor(self, &cx.crate_path("time"));
+                    quote! { #value.unwrap() }
+                }
+            }
+        }
+
+        impl TransmogrifyConst for time::$ty {
+            fn transmogrify_const_with(&self, cx: &Context) -> Result<TokenStream, ConstError> {
+                if cx.time_macros() {
+                    Ok(time_macro(cx, $macro, $text(self)))
+                } else {
+                    let value = $constructor(self, &cx.crate_path("time"));
+                    Ok(unwrap_const(cx, value))
+                }
+            }
+        }
+    };
+}
+
+fallible_impl!(Date, date, "date", date_text);
+fallible_impl!(Time, time_of_day, "time", time_text);
+fallible_impl!(UtcOffset, utc_offset, "offset", offset_text);
+
+fn date(date: &time::Date, time: &TokenStream) -> TokenStream {
+    let year = date.year();
+    let month = format_ident!("{}", date.month().to_string());
+    let day = date.day();
+    quote! {
+        #time::Date::from_calendar_date(#year, #time::Month::#month, #day)
+    }
+}
+
+fn time_of_day(value: &time::Time, time: &TokenStream) -> TokenStream {
+    let (hour, minute, second, nano) = value.as_hms_nano();
+    quote! {
+        #time::Time::from_hms_nano(#hour, #minute, #second, #nano)
+    }
+}
+
+fn utc_offset(offset: &time::UtcOffset, time: &TokenStream) -> TokenStream {
+    let (hours, minutes, seconds) = offset.as_hms();
+    quote! {
+        #time::UtcOffset::from_hms(#hours, #minutes, #seconds)
+    }
+}
+
+impl Transmogrify for time::PrimitiveDateTime {
+    fn transmogrify_with(&self, cx: &Context) -> TokenStream {
+        if cx.time_macros() {
+            let text = format!("{} {}", date_text(&self.date()), time_text(&self.time()));
+            return time_macro(cx, "datetime", text);
+        }
+        let time = cx.crate_path("time");
+        let date = cx.emit(&self.date());
+        let time_of_day = cx.emit(&self.time());
+        quote! {
+            #time::PrimitiveDateTime::new(#date, #time_of_day)
+        }
+    }
+}
+
+impl TransmogrifyConst for time::PrimitiveDateTime {
+    fn transmogrify_const_with(&self, cx: &Context) -> Result<TokenStream, ConstError> {
+        if cx.time_macros() {
+            return Ok(self.transmogrify_with(cx));
+        }
+        let time = cx.crate_path("time");
+        let date = self.date().transmogrify_const_with(cx)?;
+        let time_of_day = self.time().transmogrify_const_with(cx)?;
+        Ok(quote! {
+            #time::PrimitiveDateTime::new(#date, #time_of_day)
+        })
+    }
+}
+
+/// An `OffsetDateTime` is emitted as its date and time in its own offset.
+impl Transmogrify for time::OffsetDateTime {
+    fn transmogrify_with(&self, cx: &Context) -> TokenStream {
+        if cx.time_macros() {
+            let text = format!(
+                "{} {} {}",
+                date_text(&self.date()),
+                time_text(&self.time()),
+                offset_text(&self.offset()),
+            );
+            return time_macro(cx, "datetime", text);
+        }
+        let time = cx.crate_path("time");
+        let local = cx.emit(&time::PrimitiveDateTime::new(self.date(), self.time()));
+        let offset = cx.emit(&self.offset());
+        quote! {
+            #time::PrimitiveDateTime::assume_offset(#local, #offset)
+        }
+    }
+}
+
+impl TransmogrifyConst for time::OffsetDateTime {
+    fn transmogrify_const_with(&self, cx: &Context) -> Result<TokenStream, ConstError> {
+        if cx.time_macros() {
+            return Ok(self.transmogrify_with(cx));
+        }
+        let time = cx.crate_path("time");
+        let local =
+            time::PrimitiveDateTime::new(self.date(), self.time()).transmogrify_const_with(cx)?;
+        let offset = self.offset().transmogrify_const_with(cx)?;
+        Ok(quote! {
+            #time::PrimitiveDateTime::assume_offset(#local, #offset)
+        })
+    }
+}
+
+impl Transmogrify for time::Duration {
+    fn transmogrify_with(&self, cx: &Context) -> TokenStream {
+        let time = cx.crate_path("time");
+        let secs = self.whole_seconds();
+        let nanos = self.subsec_nanoseconds();
+        quote! {
+            #time::Duration::new(#secs, #nanos)
+        }
+    }
+}
+
+impl TransmogrifyConst for time::Duration {
+    fn transmogrify_const_with(&self, cx: &Context) -> Result<TokenStream, ConstError> {
+        Ok(self.transmogrify_with(cx))
+    }
+}
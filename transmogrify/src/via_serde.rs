@@ -0,0 +1,51 @@
+use quote::quote;
+
+use crate::{serde_json_value::raw_string, Context, Transmogrify};
+
+/// Emit a value as its JSON serialization and a call to deserialize it, rather
+/// than as a structural expression. See [`via_serde`].
+pub struct ViaSerde<'a, T: ?Sized>(pub &'a T);
+
+/// Wrap a value so that it is transmogrified via serde: the output is a JSON
+/// string literal and a call to `serde_json::from_str`. For large values, such
+/// as lookup tables with many entries, this is much faster to compile than the
+/// equivalent structural expression at the cost of parsing at runtime.
+///
+/// The value's type must implement `serde::Serialize`; where the output is
+/// used, it must implement `serde::de::DeserializeOwned` and be inferable. A
+/// value that fails to serialize, such as a map with non-string keys, is
+/// emitted as a `compile_error!`.
+///
+/// ```
+/// use transmogrify::{via_serde, Transmogrify};
+///
+/// let table = vec![(1u32, "one".to_string()), (2, "two".to_string())];
+/// let value = via_serde(&table).transmogrify();
+/// let tokens = quote::quote! {
+///     let table: Vec<(u32, String)> = #value;
+/// };
+/// ```
+pub fn via_serde<T: serde::Serialize + ?Sized>(value: &T) -> ViaSerde<'_, T> {
+    ViaSerde(value)
+}
+
+impl<T: serde::Serialize + ?Sized> Transmogrify for ViaSerde<'_, T> {
+    fn transmogrify_with(&self, cx: &Context) -> proc_macro2::TokenStream {
+        let serde_json = cx.crate_path("serde_json");
+        match serde_json::to_string(self.0) {
+            Ok(json) => {
+                let json = raw_string(&json);
+                quote! {
+                    #serde_json::from_str(#json).unwrap()
+                }
+            }
+            Err(e) => {
+                let core = cx.core_path();
+                let message = format!("value could not be serialized: {}", e);
+                quote! {
+                    #core::compile_error!(#message)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Shared by the integration tests. A test emits code for a set of values and
+//! compares it against a file in `tests/data`; it also compiles that file with
+//! `compiled!` so that it can check the values the code produces against the
+//! values it came from.
+
+// Not every test uses every helper.
+#![allow(dead_code)]
+
+use expectorate::assert_contents;
+use prettyplease::unparse;
+use proc_macro2::TokenStream;
+use transmogrify::{Context, Transmogrify, TransmogrifyConst};
+
+/// Compile `tests/data/<name>.rs` as the module `compiled`. The module
+/// doesn't have the prelude so generated code can't depend on it.
+macro_rules! compiled {
+    ($name:literal) => {
+        #[allow(dead_code)]
+        mod compiled {
+            #![no_implicit_prelude]
+
+            ::std::include!(::std::concat!(
+                ::std::env!("CARGO_MANIFEST_DIR"),
+                "/tests/data/",
+                $name,
+                ".rs",
+            ));
+        }
+    };
+}
+
+/// Emit `values` as an array returned by the function `name`.
+pub fn emit_fn<T: Transmogrify>(
+    name: &str,
+    ty: syn::Type,
+    values: &[T],
+    cx: &Context,
+) -> TokenStream {
+    let func = quote::format_ident!("{}", name);
+    let len = values.len();
+    let values = values.iter().map(|value| value.transmogrify_with(cx));
+    quote::quote! {
+        pub fn #func() -> [#ty; #len] {
+            [ #( #values, )* ]
+        }
+    }
+}
+
+/// Emit the const forms of `values` as an array constant named `name` in
+/// upper case.
+pub fn emit_const<T: TransmogrifyConst>(
+    name: &str,
+    ty: syn::Type,
+    values: &[T],
+    cx: &Context,
+) -> TokenStream {
+    let konst = quote::format_ident!("{}", name.to_uppercase());
+    let len = values.len();
+    let consts = values
+        .iter()
+        .map(|value| value.transmogrify_const_with(cx).unwrap());
+    quote::quote! {
+        pub const #konst: [#ty; #len] = [ #( #consts, )* ];
+    }
+}
+
+/// Both `emit_const` and `emit_fn`, for types whose const and runtime forms
+/// have the same type.
+pub fn emit_fn_and_const<T: Transmogrify + TransmogrifyConst>(
+    name: &str,
+    ty: syn::Type,
+    values: &[T],
+    cx: &Context,
+) -> TokenStream {
+    let konst = emit_const(name, ty.clone(), values, cx);
+    let func = emit_fn(name, ty, values, cx);
+    quote::quote! {
+        #konst
+        #func
+    }
+}
+
+/// Compare `items` against `tests/data/<name>.rs`.
+pub fn assert_output(name: &str, items: TokenStream) {
+    let file = syn::parse_quote! {
+        #items
+    };
+
+    let actual = unparse(&file);
+    assert_contents(format!("tests/data/{}.rs", name), &actual)
+}
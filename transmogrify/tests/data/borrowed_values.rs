@@ -0,0 +1,26 @@
+pub fn strs() -> [&'static str; 2usize] {
+    ["s", "\"escaped\"\n"]
+}
+pub fn slices() -> [&'static [u32]; 2usize] {
+    [&[1u32, 2u32], &[]]
+}
+pub fn cows() -> [::std::borrow::Cow<'static, str>; 2usize] {
+    [
+        ::std::borrow::Cow::Owned(
+            <::std::string::String as ::std::convert::From<&str>>::from("a"),
+        ),
+        ::std::borrow::Cow::Owned(
+            <::std::string::String as ::std::convert::From<&str>>::from("b"),
+        ),
+    ]
+}
+pub fn borrowed_cows() -> [::std::borrow::Cow<'static, str>; 2usize] {
+    [::std::borrow::Cow::Borrowed("a"), ::std::borrow::Cow::Borrowed("b")]
+}
+pub fn byte_cows() -> [::std::borrow::Cow<'static, [u8]>; 2usize] {
+    [::std::borrow::Cow::Borrowed(&[1u8, 2u8]), ::std::borrow::Cow::Borrowed(&[3u8])]
+}
+pub const COWS: [::std::borrow::Cow<'static, str>; 2usize] = [
+    ::std::borrow::Cow::Borrowed("a"),
+    ::std::borrow::Cow::Borrowed("b"),
+];
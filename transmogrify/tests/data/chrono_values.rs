@@ -0,0 +1,366 @@
+pub const DATES: [::chrono::NaiveDate; 3usize] = [
+    const {
+        match ::chrono::NaiveDate::from_ymd_opt(-262143i32, 1u32, 1u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::NaiveDate::from_ymd_opt(262142i32, 12u32, 31u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::NaiveDate::from_ymd_opt(2000i32, 2u32, 29u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+];
+pub fn dates() -> [::chrono::NaiveDate; 3usize] {
+    [
+        ::chrono::NaiveDate::from_ymd_opt(-262143i32, 1u32, 1u32).unwrap(),
+        ::chrono::NaiveDate::from_ymd_opt(262142i32, 12u32, 31u32).unwrap(),
+        ::chrono::NaiveDate::from_ymd_opt(2000i32, 2u32, 29u32).unwrap(),
+    ]
+}
+pub const TIMES: [::chrono::NaiveTime; 3usize] = [
+    const {
+        match ::chrono::NaiveTime::from_hms_nano_opt(0u32, 0u32, 0u32, 0u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::NaiveTime::from_hms_nano_opt(23u32, 59u32, 59u32, 999999999u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::NaiveTime::from_hms_nano_opt(
+            23u32,
+            59u32,
+            59u32,
+            1500000000u32,
+        ) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+];
+pub fn times() -> [::chrono::NaiveTime; 3usize] {
+    [
+        ::chrono::NaiveTime::from_hms_nano_opt(0u32, 0u32, 0u32, 0u32).unwrap(),
+        ::chrono::NaiveTime::from_hms_nano_opt(23u32, 59u32, 59u32, 999999999u32)
+            .unwrap(),
+        ::chrono::NaiveTime::from_hms_nano_opt(23u32, 59u32, 59u32, 1500000000u32)
+            .unwrap(),
+    ]
+}
+pub const DATE_TIMES: [::chrono::NaiveDateTime; 3usize] = [
+    ::chrono::NaiveDateTime::new(
+        const {
+            match ::chrono::NaiveDate::from_ymd_opt(-262143i32, 1u32, 1u32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+        const {
+            match ::chrono::NaiveTime::from_hms_nano_opt(0u32, 0u32, 0u32, 0u32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+    ::chrono::NaiveDateTime::new(
+        const {
+            match ::chrono::NaiveDate::from_ymd_opt(262142i32, 12u32, 31u32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+        const {
+            match ::chrono::NaiveTime::from_hms_nano_opt(
+                23u32,
+                59u32,
+                59u32,
+                999999999u32,
+            ) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+    ::chrono::NaiveDateTime::new(
+        const {
+            match ::chrono::NaiveDate::from_ymd_opt(2000i32, 2u32, 29u32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+        const {
+            match ::chrono::NaiveTime::from_hms_nano_opt(
+                23u32,
+                59u32,
+                59u32,
+                1500000000u32,
+            ) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+];
+pub fn date_times() -> [::chrono::NaiveDateTime; 3usize] {
+    [
+        ::chrono::NaiveDateTime::new(
+            ::chrono::NaiveDate::from_ymd_opt(-262143i32, 1u32, 1u32).unwrap(),
+            ::chrono::NaiveTime::from_hms_nano_opt(0u32, 0u32, 0u32, 0u32).unwrap(),
+        ),
+        ::chrono::NaiveDateTime::new(
+            ::chrono::NaiveDate::from_ymd_opt(262142i32, 12u32, 31u32).unwrap(),
+            ::chrono::NaiveTime::from_hms_nano_opt(23u32, 59u32, 59u32, 999999999u32)
+                .unwrap(),
+        ),
+        ::chrono::NaiveDateTime::new(
+            ::chrono::NaiveDate::from_ymd_opt(2000i32, 2u32, 29u32).unwrap(),
+            ::chrono::NaiveTime::from_hms_nano_opt(23u32, 59u32, 59u32, 1500000000u32)
+                .unwrap(),
+        ),
+    ]
+}
+pub const OFFSETS: [::chrono::FixedOffset; 2usize] = [
+    const {
+        match ::chrono::FixedOffset::east_opt(20700i32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::FixedOffset::east_opt(-86399i32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+];
+pub fn offsets() -> [::chrono::FixedOffset; 2usize] {
+    [
+        ::chrono::FixedOffset::east_opt(20700i32).unwrap(),
+        ::chrono::FixedOffset::east_opt(-86399i32).unwrap(),
+    ]
+}
+pub const UTC_DATE_TIMES: [::chrono::DateTime<::chrono::Utc>; 2usize] = [
+    const {
+        match ::chrono::DateTime::<
+            ::chrono::Utc,
+        >::from_timestamp(-8334601228800i64, 0u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::DateTime::<::chrono::Utc>::from_timestamp(1000000000i64, 42u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+];
+pub fn utc_date_times() -> [::chrono::DateTime<::chrono::Utc>; 2usize] {
+    [
+        ::chrono::DateTime::<::chrono::Utc>::from_timestamp(-8334601228800i64, 0u32)
+            .unwrap(),
+        ::chrono::DateTime::<::chrono::Utc>::from_timestamp(1000000000i64, 42u32)
+            .unwrap(),
+    ]
+}
+pub const FIXED_DATE_TIMES: [::chrono::DateTime<::chrono::FixedOffset>; 2usize] = [
+    ::chrono::DateTime::<
+        ::chrono::FixedOffset,
+    >::from_naive_utc_and_offset(
+        ::chrono::NaiveDateTime::new(
+            const {
+                match ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+            const {
+                match ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+        ),
+        const {
+            match ::chrono::FixedOffset::east_opt(20700i32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+    ::chrono::DateTime::<
+        ::chrono::FixedOffset,
+    >::from_naive_utc_and_offset(
+        ::chrono::NaiveDateTime::new(
+            const {
+                match ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+            const {
+                match ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+        ),
+        const {
+            match ::chrono::FixedOffset::east_opt(-86399i32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+];
+pub fn fixed_date_times() -> [::chrono::DateTime<::chrono::FixedOffset>; 2usize] {
+    [
+        ::chrono::DateTime::<
+            ::chrono::FixedOffset,
+        >::from_naive_utc_and_offset(
+            ::chrono::NaiveDateTime::new(
+                ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32).unwrap(),
+                ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32)
+                    .unwrap(),
+            ),
+            ::chrono::FixedOffset::east_opt(20700i32).unwrap(),
+        ),
+        ::chrono::DateTime::<
+            ::chrono::FixedOffset,
+        >::from_naive_utc_and_offset(
+            ::chrono::NaiveDateTime::new(
+                ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32).unwrap(),
+                ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32)
+                    .unwrap(),
+            ),
+            ::chrono::FixedOffset::east_opt(-86399i32).unwrap(),
+        ),
+    ]
+}
+pub const LOCAL_DATE_TIMES: [::chrono::DateTime<::chrono::Local>; 2usize] = [
+    ::chrono::DateTime::<
+        ::chrono::Local,
+    >::from_naive_utc_and_offset(
+        ::chrono::NaiveDateTime::new(
+            const {
+                match ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+            const {
+                match ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+        ),
+        const {
+            match ::chrono::FixedOffset::east_opt(20700i32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+    ::chrono::DateTime::<
+        ::chrono::Local,
+    >::from_naive_utc_and_offset(
+        ::chrono::NaiveDateTime::new(
+            const {
+                match ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+            const {
+                match ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            },
+        ),
+        const {
+            match ::chrono::FixedOffset::east_opt(-86399i32) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => ::std::panic!(),
+            }
+        },
+    ),
+];
+pub fn local_date_times() -> [::chrono::DateTime<::chrono::Local>; 2usize] {
+    [
+        ::chrono::DateTime::<
+            ::chrono::Local,
+        >::from_naive_utc_and_offset(
+            ::chrono::NaiveDateTime::new(
+                ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32).unwrap(),
+                ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32)
+                    .unwrap(),
+            ),
+            ::chrono::FixedOffset::east_opt(20700i32).unwrap(),
+        ),
+        ::chrono::DateTime::<
+            ::chrono::Local,
+        >::from_naive_utc_and_offset(
+            ::chrono::NaiveDateTime::new(
+                ::chrono::NaiveDate::from_ymd_opt(2001i32, 9u32, 9u32).unwrap(),
+                ::chrono::NaiveTime::from_hms_nano_opt(1u32, 46u32, 40u32, 42u32)
+                    .unwrap(),
+            ),
+            ::chrono::FixedOffset::east_opt(-86399i32).unwrap(),
+        ),
+    ]
+}
+pub const DELTAS: [::chrono::TimeDelta; 3usize] = [
+    const {
+        match ::chrono::TimeDelta::new(-9223372036854776i64, 193000000u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::TimeDelta::new(9223372036854775i64, 807000000u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+    const {
+        match ::chrono::TimeDelta::new(-2i64, 500000000u32) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    },
+];
+pub fn deltas() -> [::chrono::TimeDelta; 3usize] {
+    [
+        ::chrono::TimeDelta::new(-9223372036854776i64, 193000000u32).unwrap(),
+        ::chrono::TimeDelta::new(9223372036854775i64, 807000000u32).unwrap(),
+        ::chrono::TimeDelta::new(-2i64, 500000000u32).unwrap(),
+    ]
+}
+pub const WEEKDAYS: [::chrono::Weekday; 2usize] = [
+    ::chrono::Weekday::Mon,
+    ::chrono::Weekday::Sun,
+];
+pub fn weekdays() -> [::chrono::Weekday; 2usize] {
+    [::chrono::Weekday::Mon, ::chrono::Weekday::Sun]
+}
+pub const MONTHS: [::chrono::Month; 2usize] = [
+    ::chrono::Month::January,
+    ::chrono::Month::December,
+];
+pub fn months() -> [::chrono::Month; 2usize] {
+    [::chrono::Month::January, ::chrono::Month::December]
+}
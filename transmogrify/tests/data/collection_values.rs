@@ -0,0 +1,38 @@
+pub fn deques() -> [::std::collections::VecDeque<u8>; 2usize] {
+    [
+        <::std::collections::VecDeque<_> as ::std::convert::From<_>>::from([1u8, 2u8]),
+        <::std::collections::VecDeque<_>>::new(),
+    ]
+}
+pub fn heaps() -> [::std::collections::BinaryHeap<u8>; 2usize] {
+    [
+        <::std::collections::BinaryHeap<
+            _,
+        > as ::std::convert::From<_>>::from([1u8, 2u8, 3u8]),
+        <::std::collections::BinaryHeap<_>>::new(),
+    ]
+}
+pub fn lists() -> [::std::collections::LinkedList<u8>; 2usize] {
+    [
+        <::std::collections::LinkedList<_> as ::std::convert::From<_>>::from([1u8, 2u8]),
+        <::std::collections::LinkedList<_>>::new(),
+    ]
+}
+pub fn boxed_slices() -> [::std::boxed::Box<[u8]>; 2usize] {
+    [
+        <::std::boxed::Box<[_]> as ::std::convert::From<_>>::from([1u8, 2u8]),
+        <::std::boxed::Box<[_]> as ::std::default::Default>::default(),
+    ]
+}
+pub fn boxed_strs() -> [::std::boxed::Box<str>; 2usize] {
+    [
+        <::std::boxed::Box<str> as ::std::convert::From<&str>>::from("s"),
+        <::std::boxed::Box<str> as ::std::convert::From<&str>>::from(""),
+    ]
+}
+pub fn rc_strs() -> [::std::rc::Rc<str>; 1usize] {
+    [<::std::rc::Rc<str> as ::std::convert::From<&str>>::from("s")]
+}
+pub fn arc_strs() -> [::std::sync::Arc<str>; 1usize] {
+    [<::std::sync::Arc<str> as ::std::convert::From<&str>>::from("\"s\"")]
+}
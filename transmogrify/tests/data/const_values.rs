@@ -0,0 +1,29 @@
+pub const STRINGS: [&[&str]; 2usize] = [&["a", "b"], &[]];
+pub const BOXES: [&u8; 1usize] = [&1u8];
+pub const OPTIONS: [::std::option::Option<::std::num::NonZeroU16>; 2usize] = [
+    ::std::option::Option::Some(const {
+        match ::std::num::NonZeroU16::new(65535u16) {
+            ::std::option::Option::Some(value) => value,
+            ::std::option::Option::None => ::std::panic!(),
+        }
+    }),
+    ::std::option::Option::None,
+];
+pub const MAPS: [::std::collections::BTreeMap<u8, u8>; 1usize] = [
+    <::std::collections::BTreeMap<_, _>>::new(),
+];
+pub const DERIVED: [crate::TestConst; 1usize] = [
+    crate::TestConst {
+        name: "n",
+        values: &[
+            ::std::option::Option::None,
+            ::std::option::Option::Some(const {
+                match ::std::num::NonZeroU8::new(1u8) {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::panic!(),
+                }
+            }),
+        ],
+        count: 0,
+    },
+];
@@ -0,0 +1,46 @@
+pub fn values() -> [crate::inner::TestStructRoot; 2usize] {
+    [
+        crate::inner::TestStructRoot {
+            values: <crate::reexports::std::vec::Vec<
+                _,
+            > as crate::reexports::std::convert::From<_>>::from([1u32, 2u32]),
+            time: crate::reexports::chrono::DateTime::<
+                crate::reexports::chrono::Utc,
+            >::from_timestamp(0i64, 0u32)
+                .unwrap(),
+            name: crate::reexports::std::option::Option::Some(
+                <crate::reexports::std::string::String as crate::reexports::std::convert::From<
+                    &str,
+                >>::from("a"),
+            ),
+        },
+        crate::inner::TestStructRoot {
+            values: <crate::reexports::std::vec::Vec<_>>::new(),
+            time: crate::reexports::chrono::DateTime::<
+                crate::reexports::chrono::Utc,
+            >::from_timestamp(8210266876799i64, 999999999u32)
+                .unwrap(),
+            name: crate::reexports::std::option::Option::None,
+        },
+    ]
+}
+pub fn prefixed() -> [crate::inner::TestStructPrefixed; 1usize] {
+    [
+        crate::inner::TestStructPrefixed {
+            root: crate::inner::TestStructRoot {
+                values: <crate::reexports::std::vec::Vec<
+                    _,
+                > as crate::reexports::std::convert::From<_>>::from([1u32, 2u32]),
+                time: crate::reexports::chrono::DateTime::<
+                    crate::reexports::chrono::Utc,
+                >::from_timestamp(0i64, 0u32)
+                    .unwrap(),
+                name: crate::reexports::std::option::Option::Some(
+                    <crate::reexports::std::string::String as crate::reexports::std::convert::From<
+                        &str,
+                    >>::from("a"),
+                ),
+            },
+        },
+    ]
+}
@@ -0,0 +1,87 @@
+pub fn table() -> ::std::collections::HashMap<
+    ::std::string::String,
+    (
+        ::std::vec::Vec<::std::string::String>,
+        ::std::vec::Vec<::std::vec::Vec<::std::string::String>>,
+    ),
+> {
+    {
+        let __tm_0 = <::std::vec::Vec<
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([
+            <::std::string::String as ::std::convert::From<&str>>::from("a"),
+            <::std::string::String as ::std::convert::From<&str>>::from("long"),
+            <::std::string::String as ::std::convert::From<&str>>::from("list"),
+        ]);
+        <::std::collections::HashMap<
+            _,
+            _,
+            _,
+        > as ::std::iter::FromIterator<
+            _,
+        >>::from_iter([
+            (
+                <::std::string::String as ::std::convert::From<&str>>::from("x"),
+                (
+                    ::std::clone::Clone::clone(&__tm_0),
+                    <::std::vec::Vec<
+                        _,
+                    > as ::std::convert::From<
+                        _,
+                    >>::from([
+                        ::std::clone::Clone::clone(&__tm_0),
+                        ::std::clone::Clone::clone(&__tm_0),
+                    ]),
+                ),
+            ),
+            (
+                <::std::string::String as ::std::convert::From<&str>>::from("y"),
+                (
+                    <::std::vec::Vec<_>>::new(),
+                    <::std::vec::Vec<
+                        _,
+                    > as ::std::convert::From<
+                        _,
+                    >>::from([::std::clone::Clone::clone(&__tm_0)]),
+                ),
+            ),
+        ])
+    }
+}
+pub fn strings() -> ::std::vec::Vec<::std::string::String> {
+    {
+        let __tm_0 = <::std::string::String as ::std::convert::From<
+            &str,
+        >>::from(
+            "01234567890123456789012345678901234567890123456789012345678901234567890123456789",
+        );
+        <::std::vec::Vec<
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([
+            ::std::clone::Clone::clone(&__tm_0),
+            ::std::clone::Clone::clone(&__tm_0),
+            ::std::clone::Clone::clone(&__tm_0),
+        ])
+    }
+}
+pub fn bytes() -> ::std::vec::Vec<[u8; 64]> {
+    {
+        let __tm_0 = *b"\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5\xA5";
+        <::std::vec::Vec<
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([
+            ::std::clone::Clone::clone(&__tm_0),
+            ::std::clone::Clone::clone(&__tm_0),
+            *b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        ])
+    }
+}
+pub fn small() -> ::std::vec::Vec<u8> {
+    <::std::vec::Vec<_> as ::std::convert::From<_>>::from([1u8, 1u8, 1u8])
+}
@@ -0,0 +1,92 @@
+pub const F64S: [f64; 20usize] = [
+    ::std::primitive::f64::NAN,
+    ::std::primitive::f64::from_bits(0xfff8000000000000),
+    ::std::primitive::f64::INFINITY,
+    ::std::primitive::f64::NEG_INFINITY,
+    0f64,
+    -0f64,
+    -179769313486231570000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+    0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000022250738585072014f64,
+    0.1f64,
+    ::std::primitive::f64::from_bits(0x7ff0000000000001),
+    ::std::primitive::f64::from_bits(0xfff8000000000001),
+    0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005f64,
+    -13179096909480504000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+    0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000016818219269159687f64,
+    345627938119274560000000000000000f64,
+    -96421623.15697995f64,
+    -30367038646169440000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+    -0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004733182181099115f64,
+    0.0000000000000000000024029285922608497f64,
+    48388244681836510000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+];
+pub fn f64s() -> [f64; 20usize] {
+    [
+        ::std::primitive::f64::NAN,
+        ::std::primitive::f64::from_bits(0xfff8000000000000),
+        ::std::primitive::f64::INFINITY,
+        ::std::primitive::f64::NEG_INFINITY,
+        0f64,
+        -0f64,
+        -179769313486231570000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+        0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000022250738585072014f64,
+        0.1f64,
+        ::std::primitive::f64::from_bits(0x7ff0000000000001),
+        ::std::primitive::f64::from_bits(0xfff8000000000001),
+        0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005f64,
+        -13179096909480504000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+        0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000016818219269159687f64,
+        345627938119274560000000000000000f64,
+        -96421623.15697995f64,
+        -30367038646169440000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+        -0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004733182181099115f64,
+        0.0000000000000000000024029285922608497f64,
+        48388244681836510000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f64,
+    ]
+}
+pub const F32S: [f32; 20usize] = [
+    ::std::primitive::f32::NAN,
+    ::std::primitive::f32::from_bits(0xffc00000),
+    ::std::primitive::f32::INFINITY,
+    ::std::primitive::f32::NEG_INFINITY,
+    0f32,
+    -0f32,
+    340282350000000000000000000000000000000f32,
+    0.00000011920929f32,
+    0.1f32,
+    ::std::primitive::f32::from_bits(0x7f800001),
+    ::std::primitive::f32::from_bits(0xffc00001),
+    0.000000000000000000000000000000000000000000001f32,
+    -0.0000007679337f32,
+    -25673658000000000000000000000000000f32,
+    -0.025751518f32,
+    0.0000071074496f32,
+    -2466607000000000000000000000000000000f32,
+    2214817000000000f32,
+    -4773551700000000000f32,
+    988807000000000000000000000000000f32,
+];
+pub fn f32s() -> [f32; 20usize] {
+    [
+        ::std::primitive::f32::NAN,
+        ::std::primitive::f32::from_bits(0xffc00000),
+        ::std::primitive::f32::INFINITY,
+        ::std::primitive::f32::NEG_INFINITY,
+        0f32,
+        -0f32,
+        340282350000000000000000000000000000000f32,
+        0.00000011920929f32,
+        0.1f32,
+        ::std::primitive::f32::from_bits(0x7f800001),
+        ::std::primitive::f32::from_bits(0xffc00001),
+        0.000000000000000000000000000000000000000000001f32,
+        -0.0000007679337f32,
+        -25673658000000000000000000000000000f32,
+        -0.025751518f32,
+        0.0000071074496f32,
+        -2466607000000000000000000000000000000f32,
+        2214817000000000f32,
+        -4773551700000000000f32,
+        988807000000000000000000000000000f32,
+    ]
+}
@@ -0,0 +1,53 @@
+pub fn maps() -> [::std::collections::HashMap<u8, bool>; 2usize] {
+    [
+        <::std::collections::HashMap<
+            _,
+            _,
+            _,
+        > as ::std::iter::FromIterator<
+            _,
+        >>::from_iter([
+            (0u8, true),
+            (10u8, true),
+            (11u8, false),
+            (1u8, false),
+            (2u8, true),
+            (3u8, false),
+            (4u8, true),
+            (5u8, false),
+            (6u8, true),
+            (7u8, false),
+            (8u8, true),
+            (9u8, false),
+        ]),
+        <::std::collections::HashMap<_, _, _> as ::std::default::Default>::default(),
+    ]
+}
+pub fn fixed_maps() -> [::std::collections::HashMap<u8, u8, crate::Fixed>; 2usize] {
+    [
+        <::std::collections::HashMap<
+            _,
+            _,
+            _,
+        > as ::std::iter::FromIterator<_>>::from_iter([(1u8, 2u8), (3u8, 4u8)]),
+        <::std::collections::HashMap<_, _, _> as ::std::default::Default>::default(),
+    ]
+}
+pub fn sets() -> [::std::collections::HashSet<&'static str>; 2usize] {
+    [
+        <::std::collections::HashSet<
+            _,
+            _,
+        > as ::std::iter::FromIterator<_>>::from_iter(["a", "b", "c"]),
+        <::std::collections::HashSet<_, _> as ::std::default::Default>::default(),
+    ]
+}
+pub fn fixed_sets() -> [::std::collections::HashSet<u8, crate::Fixed>; 2usize] {
+    [
+        <::std::collections::HashSet<
+            _,
+            _,
+        > as ::std::iter::FromIterator<_>>::from_iter([1u8, 2u8]),
+        <::std::collections::HashSet<_, _> as ::std::default::Default>::default(),
+    ]
+}
@@ -0,0 +1,138 @@
+pub fn values() -> [::serde_json::Value; 2usize] {
+    [
+        ::serde_json::Value::Object(
+            <::serde_json::Map<
+                ::std::string::String,
+                ::serde_json::Value,
+            > as ::std::iter::FromIterator<
+                _,
+            >>::from_iter([
+                (
+                    <::std::string::String as ::std::convert::From<&str>>::from("array"),
+                    ::serde_json::Value::Array(
+                        <::std::vec::Vec<
+                            _,
+                        > as ::std::convert::From<
+                            _,
+                        >>::from([
+                            ::serde_json::Value::Number(
+                                <::serde_json::Number as ::std::convert::From<
+                                    u64,
+                                >>::from(1u64),
+                            ),
+                            ::serde_json::Value::Array(<::std::vec::Vec<_>>::new()),
+                            ::serde_json::Value::Object(::serde_json::Map::new()),
+                        ]),
+                    ),
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<&str>>::from("bool"),
+                    ::serde_json::Value::Bool(true),
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<&str>>::from("float"),
+                    ::serde_json::Value::Number(
+                        ::serde_json::Number::from_f64(0.1f64).unwrap(),
+                    ),
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<&str>>::from("null"),
+                    ::serde_json::Value::Null,
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<
+                        &str,
+                    >>::from("signed"),
+                    ::serde_json::Value::Number(
+                        <::serde_json::Number as ::std::convert::From<
+                            i64,
+                        >>::from(-9223372036854775808i64),
+                    ),
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<
+                        &str,
+                    >>::from("string"),
+                    ::serde_json::Value::String(
+                        <::std::string::String as ::std::convert::From<&str>>::from("s"),
+                    ),
+                ),
+                (
+                    <::std::string::String as ::std::convert::From<
+                        &str,
+                    >>::from("unsigned"),
+                    ::serde_json::Value::Number(
+                        <::serde_json::Number as ::std::convert::From<
+                            u64,
+                        >>::from(18446744073709551615u64),
+                    ),
+                ),
+            ]),
+        ),
+        ::serde_json::Value::Null,
+    ]
+}
+pub fn numbers() -> [::serde_json::Number; 3usize] {
+    [
+        <::serde_json::Number as ::std::convert::From<
+            u64,
+        >>::from(18446744073709551615u64),
+        <::serde_json::Number as ::std::convert::From<
+            i64,
+        >>::from(-9223372036854775808i64),
+        ::serde_json::Number::from_f64(-0.5f64).unwrap(),
+    ]
+}
+pub fn maps() -> [::serde_json::Map<
+    ::std::string::String,
+    ::serde_json::Value,
+>; 2usize] {
+    [
+        <::serde_json::Map<
+            ::std::string::String,
+            ::serde_json::Value,
+        > as ::std::iter::FromIterator<
+            _,
+        >>::from_iter([
+            (
+                <::std::string::String as ::std::convert::From<&str>>::from("a"),
+                ::serde_json::Value::Array(
+                    <::std::vec::Vec<
+                        _,
+                    > as ::std::convert::From<
+                        _,
+                    >>::from([::serde_json::Value::Bool(true)]),
+                ),
+            ),
+            (
+                <::std::string::String as ::std::convert::From<&str>>::from("b"),
+                ::serde_json::Value::Number(
+                    <::serde_json::Number as ::std::convert::From<u64>>::from(1u64),
+                ),
+            ),
+        ]),
+        ::serde_json::Map::new(),
+    ]
+}
+pub fn thresholded() -> [::serde_json::Value; 2usize] {
+    [
+        ::serde_json::Value::Array(
+            <::std::vec::Vec<
+                _,
+            > as ::std::convert::From<
+                _,
+            >>::from([
+                ::serde_json::Value::Number(
+                    <::serde_json::Number as ::std::convert::From<u64>>::from(1u64),
+                ),
+                ::serde_json::Value::Number(
+                    <::serde_json::Number as ::std::convert::From<u64>>::from(2u64),
+                ),
+            ]),
+        ),
+        ::serde_json::from_str::<
+            ::serde_json::Value,
+        >(r##"{"key":["a\"#b",{"nested":1.5}]}"##)
+            .unwrap(),
+    ]
+}
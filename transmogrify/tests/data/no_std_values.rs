@@ -0,0 +1,60 @@
+pub fn maps() -> [::alloc::collections::BTreeMap<
+    u8,
+    ::alloc::vec::Vec<
+        ::core::option::Option<::alloc::boxed::Box<::core::num::NonZeroI32>>,
+    >,
+>; 1usize] {
+    [
+        <::alloc::collections::BTreeMap<
+            _,
+            _,
+        > as ::core::convert::From<
+            _,
+        >>::from([
+            (
+                1u8,
+                <::alloc::vec::Vec<
+                    _,
+                > as ::core::convert::From<
+                    _,
+                >>::from([
+                    ::core::option::Option::Some(
+                        ::alloc::boxed::Box::new(
+                            ::core::num::NonZeroI32::new(-2147483648i32).unwrap(),
+                        ),
+                    ),
+                    ::core::option::Option::None,
+                ]),
+            ),
+        ]),
+    ]
+}
+pub fn sets() -> [::alloc::collections::BTreeSet<::alloc::string::String>; 1usize] {
+    [
+        <::alloc::collections::BTreeSet<
+            _,
+        > as ::core::convert::From<
+            _,
+        >>::from([<::alloc::string::String as ::core::convert::From<&str>>::from("s")]),
+    ]
+}
+pub fn derived() -> [crate::TestNoStd; 1usize] {
+    [
+        crate::TestNoStd {
+            name: ::core::option::Option::Some(
+                <::alloc::string::String as ::core::convert::From<&str>>::from("a"),
+            ),
+            cache: ::core::default::Default::default(),
+        },
+    ]
+}
+pub fn converted() -> [crate::TestNoStdFrom; 1usize] {
+    [
+        <crate::TestNoStdFrom as ::core::convert::From<
+            _,
+        >>::from(crate::TestNoStd {
+            name: ::core::option::Option::None,
+            cache: ::core::default::Default::default(),
+        }),
+    ]
+}
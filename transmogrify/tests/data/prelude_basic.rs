@@ -0,0 +1,37 @@
+fn main() {
+    let _: ::std::collections::BTreeMap<
+        ::std::string::String,
+        ::std::vec::Vec<::std::option::Option<::std::boxed::Box<::std::num::NonZeroU8>>>,
+    > = <::std::collections::BTreeMap<
+        _,
+        _,
+    > as ::std::convert::From<
+        _,
+    >>::from([
+        (
+            <::std::string::String as ::std::convert::From<&str>>::from("a"),
+            <::std::vec::Vec<
+                _,
+            > as ::std::convert::From<
+                _,
+            >>::from([
+                ::std::option::Option::Some(
+                    ::std::boxed::Box::new(::std::num::NonZeroU8::new(1u8).unwrap()),
+                ),
+                ::std::option::Option::None,
+            ]),
+        ),
+    ]);
+    let _: ::std::collections::BTreeMap<::std::string::String, u8> = <::std::collections::BTreeMap<
+        _,
+        _,
+    >>::new();
+    let _: ::std::collections::BTreeSet<bool> = <::std::collections::BTreeSet<
+        _,
+    > as ::std::convert::From<_>>::from([true]);
+    let _: ::std::collections::BTreeSet<bool> = <::std::collections::BTreeSet<_>>::new();
+    let _: ::std::vec::Vec<u8> = <::std::vec::Vec<_>>::new();
+    let _: ::std::string::String = <::std::string::String as ::std::convert::From<
+        &str,
+    >>::from("s");
+}
@@ -0,0 +1,7 @@
+fn main() {
+    let _: crate::TestEnum = crate::TestEnum::Named {
+        name: <::std::string::String as ::std::convert::From<&str>>::from("a"),
+        cache: ::std::default::Default::default(),
+    };
+    let _: crate::TestEnum = crate::TestEnum::Unnamed(::std::option::Option::None);
+}
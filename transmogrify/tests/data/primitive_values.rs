@@ -0,0 +1,36 @@
+pub const ISIZES: [isize; 3usize] = [
+    -9223372036854775808isize,
+    -3isize,
+    9223372036854775807isize,
+];
+pub fn isizes() -> [isize; 3usize] {
+    [-9223372036854775808isize, -3isize, 9223372036854775807isize]
+}
+pub const USIZES: [usize; 2usize] = [0usize, 18446744073709551615usize];
+pub fn usizes() -> [usize; 2usize] {
+    [0usize, 18446744073709551615usize]
+}
+pub const I128S: [i128; 3usize] = [
+    -170141183460469231731687303715884105728i128,
+    -1i128,
+    170141183460469231731687303715884105727i128,
+];
+pub fn i128s() -> [i128; 3usize] {
+    [
+        -170141183460469231731687303715884105728i128,
+        -1i128,
+        170141183460469231731687303715884105727i128,
+    ]
+}
+pub const U128S: [u128; 2usize] = [0u128, 340282366920938463463374607431768211455u128];
+pub fn u128s() -> [u128; 2usize] {
+    [0u128, 340282366920938463463374607431768211455u128]
+}
+pub const CHARS: [char; 5usize] = ['a', '\'', '\\', '\0', '\u{10ffff}'];
+pub fn chars() -> [char; 5usize] {
+    ['a', '\'', '\\', '\0', '\u{10ffff}']
+}
+pub const UNITS: [(); 1usize] = [()];
+pub fn units() -> [(); 1usize] {
+    [()]
+}
@@ -0,0 +1,148 @@
+pub fn rcs() -> [::std::rc::Rc<u8>; 1usize] {
+    [::std::rc::Rc::new(1u8)]
+}
+pub fn arcs() -> [::std::sync::Arc<::std::string::String>; 1usize] {
+    [
+        ::std::sync::Arc::new(
+            <::std::string::String as ::std::convert::From<&str>>::from("s"),
+        ),
+    ]
+}
+pub fn cells() -> [::std::cell::Cell<u8>; 1usize] {
+    [::std::cell::Cell::new(1u8)]
+}
+pub fn ref_cells() -> [::std::cell::RefCell<::std::vec::Vec<u8>>; 1usize] {
+    [
+        ::std::cell::RefCell::new(
+            <::std::vec::Vec<_> as ::std::convert::From<_>>::from([1u8, 2u8]),
+        ),
+    ]
+}
+pub fn once_cells() -> [::std::cell::OnceCell<u8>; 2usize] {
+    [
+        <::std::cell::OnceCell<_> as ::std::convert::From<_>>::from(1u8),
+        ::std::cell::OnceCell::new(),
+    ]
+}
+pub fn mutexes() -> [::std::sync::Mutex<u8>; 1usize] {
+    [::std::sync::Mutex::new(1u8)]
+}
+pub fn rw_locks() -> [::std::sync::RwLock<u8>; 1usize] {
+    [::std::sync::RwLock::new(1u8)]
+}
+pub fn phantoms() -> [::std::marker::PhantomData<str>; 1usize] {
+    [::std::marker::PhantomData]
+}
+pub fn tree() -> crate::Tree {
+    {
+        let __tm_ptr_0 = ::std::rc::Rc::new(
+            <::std::string::String as ::std::convert::From<&str>>::from("leaf"),
+        );
+        let __tm_ptr_1 = ::std::rc::Rc::new(
+            <::std::vec::Vec<
+                _,
+            > as ::std::convert::From<
+                _,
+            >>::from([
+                ::std::clone::Clone::clone(&__tm_ptr_0),
+                ::std::clone::Clone::clone(&__tm_ptr_0),
+            ]),
+        );
+        <::std::vec::Vec<
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([
+            ::std::clone::Clone::clone(&__tm_ptr_1),
+            ::std::clone::Clone::clone(&__tm_ptr_1),
+            ::std::rc::Rc::new(
+                <::std::vec::Vec<
+                    _,
+                > as ::std::convert::From<
+                    _,
+                >>::from([::std::clone::Clone::clone(&__tm_ptr_0)]),
+            ),
+        ])
+    }
+}
+pub fn unshared() -> ::std::sync::Arc<u8> {
+    ::std::sync::Arc::new(1u8)
+}
+pub fn pairs() -> crate::Pairs {
+    {
+        let __tm_ptr_0 = ::std::rc::Rc::new(
+            <::std::string::String as ::std::convert::From<&str>>::from("a"),
+        );
+        let __tm_ptr_1 = ::std::rc::Rc::new(
+            <::std::string::String as ::std::convert::From<&str>>::from("b"),
+        );
+        <::std::collections::HashMap<
+            _,
+            _,
+            _,
+        > as ::std::iter::FromIterator<
+            _,
+        >>::from_iter([
+            (
+                0u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                ),
+            ),
+            (
+                1u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                ),
+            ),
+            (
+                2u8,
+                (
+                    ::std::rc::Rc::new(
+                        <::std::string::String as ::std::convert::From<&str>>::from("2"),
+                    ),
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                ),
+            ),
+            (
+                3u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                ),
+            ),
+            (
+                4u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                ),
+            ),
+            (
+                5u8,
+                (
+                    ::std::rc::Rc::new(
+                        <::std::string::String as ::std::convert::From<&str>>::from("5"),
+                    ),
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                ),
+            ),
+            (
+                6u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                ),
+            ),
+            (
+                7u8,
+                (
+                    ::std::clone::Clone::clone(&__tm_ptr_1),
+                    ::std::clone::Clone::clone(&__tm_ptr_0),
+                ),
+            ),
+        ])
+    }
+}
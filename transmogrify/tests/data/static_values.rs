@@ -0,0 +1,26 @@
+pub static MAP: ::std::sync::LazyLock<
+    ::std::collections::BTreeMap<u8, ::std::string::String>,
+> = ::std::sync::LazyLock::new(|| <::std::collections::BTreeMap<
+    _,
+    _,
+> as ::std::convert::From<
+    _,
+>>::from([
+    (1u8, <::std::string::String as ::std::convert::From<&str>>::from("a")),
+    (2u8, <::std::string::String as ::std::convert::From<&str>>::from("b")),
+]));
+pub fn map() -> &'static ::std::collections::BTreeMap<u8, ::std::string::String> {
+    static VALUE: ::std::sync::OnceLock<
+        ::std::collections::BTreeMap<u8, ::std::string::String>,
+    > = ::std::sync::OnceLock::new();
+    VALUE
+        .get_or_init(|| <::std::collections::BTreeMap<
+            _,
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([
+            (1u8, <::std::string::String as ::std::convert::From<&str>>::from("a")),
+            (2u8, <::std::string::String as ::std::convert::From<&str>>::from("b")),
+        ]))
+}
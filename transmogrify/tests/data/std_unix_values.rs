@@ -0,0 +1,18 @@
+pub fn os_strings() -> [::std::ffi::OsString; 1usize] {
+    [
+        <::std::ffi::OsString as ::std::os::unix::ffi::OsStringExt>::from_vec(
+            <::std::vec::Vec<_> as ::std::convert::From<_>>::from([97u8, 255u8]),
+        ),
+    ]
+}
+pub fn paths() -> [::std::path::PathBuf; 1usize] {
+    [
+        <::std::path::PathBuf as ::std::convert::From<
+            ::std::ffi::OsString,
+        >>::from(
+            <::std::ffi::OsString as ::std::os::unix::ffi::OsStringExt>::from_vec(
+                <::std::vec::Vec<_> as ::std::convert::From<_>>::from([97u8, 255u8]),
+            ),
+        ),
+    ]
+}
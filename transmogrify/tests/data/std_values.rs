@@ -0,0 +1,119 @@
+pub const DURATIONS: [::std::time::Duration; 3usize] = [
+    ::std::time::Duration::from_secs(3u64),
+    ::std::time::Duration::new(1u64, 5u32),
+    ::std::time::Duration::new(18446744073709551615u64, 999999999u32),
+];
+pub fn durations() -> [::std::time::Duration; 3usize] {
+    [
+        ::std::time::Duration::from_secs(3u64),
+        ::std::time::Duration::new(1u64, 5u32),
+        ::std::time::Duration::new(18446744073709551615u64, 999999999u32),
+    ]
+}
+pub fn system_times() -> [::std::time::SystemTime; 3usize] {
+    [
+        <::std::time::SystemTime as ::std::ops::Add<
+            ::std::time::Duration,
+        >>::add(
+            ::std::time::SystemTime::UNIX_EPOCH,
+            ::std::time::Duration::from_secs(0u64),
+        ),
+        <::std::time::SystemTime as ::std::ops::Add<
+            ::std::time::Duration,
+        >>::add(
+            ::std::time::SystemTime::UNIX_EPOCH,
+            ::std::time::Duration::new(1u64, 500000000u32),
+        ),
+        <::std::time::SystemTime as ::std::ops::Sub<
+            ::std::time::Duration,
+        >>::sub(
+            ::std::time::SystemTime::UNIX_EPOCH,
+            ::std::time::Duration::from_secs(2u64),
+        ),
+    ]
+}
+pub const IPV4S: [::std::net::Ipv4Addr; 2usize] = [
+    ::std::net::Ipv4Addr::new(127u8, 0u8, 0u8, 1u8),
+    ::std::net::Ipv4Addr::new(255u8, 255u8, 255u8, 255u8),
+];
+pub fn ipv4s() -> [::std::net::Ipv4Addr; 2usize] {
+    [
+        ::std::net::Ipv4Addr::new(127u8, 0u8, 0u8, 1u8),
+        ::std::net::Ipv4Addr::new(255u8, 255u8, 255u8, 255u8),
+    ]
+}
+pub const IPV6S: [::std::net::Ipv6Addr; 1usize] = [
+    ::std::net::Ipv6Addr::new(8193u16, 3512u16, 0u16, 0u16, 0u16, 0u16, 0u16, 1u16),
+];
+pub fn ipv6s() -> [::std::net::Ipv6Addr; 1usize] {
+    [::std::net::Ipv6Addr::new(8193u16, 3512u16, 0u16, 0u16, 0u16, 0u16, 0u16, 1u16)]
+}
+pub const IPS: [::std::net::IpAddr; 2usize] = [
+    ::std::net::IpAddr::V4(::std::net::Ipv4Addr::new(127u8, 0u8, 0u8, 1u8)),
+    ::std::net::IpAddr::V6(
+        ::std::net::Ipv6Addr::new(0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 1u16),
+    ),
+];
+pub fn ips() -> [::std::net::IpAddr; 2usize] {
+    [
+        ::std::net::IpAddr::V4(::std::net::Ipv4Addr::new(127u8, 0u8, 0u8, 1u8)),
+        ::std::net::IpAddr::V6(
+            ::std::net::Ipv6Addr::new(0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 1u16),
+        ),
+    ]
+}
+pub const SOCKETS: [::std::net::SocketAddr; 2usize] = [
+    ::std::net::SocketAddr::V4(
+        ::std::net::SocketAddrV4::new(
+            ::std::net::Ipv4Addr::new(10u8, 0u8, 0u8, 1u8),
+            80u16,
+        ),
+    ),
+    ::std::net::SocketAddr::V6(
+        ::std::net::SocketAddrV6::new(
+            ::std::net::Ipv6Addr::new(0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 0u16, 1u16),
+            443u16,
+            1u32,
+            2u32,
+        ),
+    ),
+];
+pub fn sockets() -> [::std::net::SocketAddr; 2usize] {
+    [
+        ::std::net::SocketAddr::V4(
+            ::std::net::SocketAddrV4::new(
+                ::std::net::Ipv4Addr::new(10u8, 0u8, 0u8, 1u8),
+                80u16,
+            ),
+        ),
+        ::std::net::SocketAddr::V6(
+            ::std::net::SocketAddrV6::new(
+                ::std::net::Ipv6Addr::new(
+                    0u16,
+                    0u16,
+                    0u16,
+                    0u16,
+                    0u16,
+                    0u16,
+                    0u16,
+                    1u16,
+                ),
+                443u16,
+                1u32,
+                2u32,
+            ),
+        ),
+    ]
+}
+pub fn os_strings() -> [::std::ffi::OsString; 2usize] {
+    [
+        <::std::ffi::OsString as ::std::convert::From<&str>>::from("s"),
+        <::std::ffi::OsString as ::std::convert::From<&str>>::from(""),
+    ]
+}
+pub fn paths() -> [::std::path::PathBuf; 2usize] {
+    [
+        <::std::path::PathBuf as ::std::convert::From<&str>>::from("/etc/hosts"),
+        <::std::path::PathBuf as ::std::convert::From<&str>>::from("relative/\"path\""),
+    ]
+}
@@ -0,0 +1,5 @@
+pub fn value() -> crate::TestStructPrivate {
+    crate::TestStructPrivate::new(
+        <::std::string::String as ::std::convert::From<&str>>::from("A"),
+    )
+}
@@ -0,0 +1,8 @@
+pub fn value() -> crate::TestRange {
+    <crate::TestRange as ::std::convert::From<
+        _,
+    >>::from(crate::TestRangeRepr {
+        start: 1u32,
+        end: 5u32,
+    })
+}
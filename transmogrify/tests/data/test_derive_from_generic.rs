@@ -0,0 +1,10 @@
+pub fn value() -> crate::TestPair<String> {
+    <crate::TestPair<
+        _,
+    > as ::std::convert::From<
+        _,
+    >>::from(crate::TestPairRepr {
+        first: <::std::string::String as ::std::convert::From<&str>>::from("a"),
+        second: <::std::string::String as ::std::convert::From<&str>>::from("b"),
+    })
+}
@@ -0,0 +1,10 @@
+pub fn value() -> crate::TestStructGeneric<String> {
+    crate::TestStructGeneric {
+        inner: <::std::string::String as ::std::convert::From<&str>>::from("A"),
+        items: <::std::vec::Vec<
+            _,
+        > as ::std::convert::From<
+            _,
+        >>::from([<::std::string::String as ::std::convert::From<&str>>::from("B")]),
+    }
+}
@@ -0,0 +1,7 @@
+pub fn value() -> crate::TestStructRoot {
+    crate::root::TestStructRoot {
+        local: TestStructLocal {
+            a: <::std::string::String as ::std::convert::From<&str>>::from("A"),
+        },
+    }
+}
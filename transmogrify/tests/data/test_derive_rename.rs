@@ -0,0 +1,7 @@
+pub fn value() -> Vec<crate::PublicName> {
+    <::std::vec::Vec<
+        _,
+    > as ::std::convert::From<
+        _,
+    >>::from([crate::PublicName::Plain(7u32), crate::Shortcut])
+}
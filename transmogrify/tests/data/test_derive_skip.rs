@@ -0,0 +1,7 @@
+pub fn value() -> crate::TestStructSkip {
+    crate::TestStructSkip {
+        a: <::std::string::String as ::std::convert::From<&str>>::from("A"),
+        cache: ::std::default::Default::default(),
+        scratch: Vec::new(),
+    }
+}
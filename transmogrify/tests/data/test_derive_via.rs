@@ -0,0 +1,3 @@
+pub fn value() -> crate::TestStructVia {
+    ::serde_json::from_str(r#"{"table":[[1,"one"],[2,"two"]]}"#).unwrap()
+}
@@ -0,0 +1,6 @@
+pub fn value() -> crate::TestStructWith {
+    crate::TestStructWith {
+        name: <::std::string::String as ::std::convert::From<&str>>::from("kitchen"),
+        temperature: crate::foreign::Celsius(21.5f64),
+    }
+}
@@ -1,160 +1,201 @@
 fn main() {
-    let _ = schemars::schema::Schema::Object(schemars::schema::SchemaObject {
-        metadata: Some(
-            Box::new(schemars::schema::Metadata {
-                id: None,
-                title: Some(String::from("PortId")),
-                description: Some(String::from("Physical switch port identifier")),
-                default: None,
+    let _ = ::schemars::schema::Schema::Object(::schemars::schema::SchemaObject {
+        metadata: ::std::option::Option::Some(
+            ::std::boxed::Box::new(::schemars::schema::Metadata {
+                id: ::std::option::Option::None,
+                title: ::std::option::Option::Some(
+                    <::std::string::String as ::std::convert::From<&str>>::from("PortId"),
+                ),
+                description: ::std::option::Option::Some(
+                    <::std::string::String as ::std::convert::From<
+                        &str,
+                    >>::from("Physical switch port identifier"),
+                ),
+                default: ::std::option::Option::None,
                 deprecated: false,
                 read_only: false,
                 write_only: false,
-                examples: <std::vec::Vec<_>>::new(),
+                examples: <::std::vec::Vec<_>>::new(),
             }),
         ),
-        instance_type: None,
-        format: None,
-        enum_values: None,
-        const_value: None,
-        subschemas: Some(
-            Box::new(schemars::schema::SubschemaValidation {
-                all_of: None,
-                any_of: None,
-                one_of: Some(
-                    <std::vec::Vec<
+        instance_type: ::std::option::Option::None,
+        format: ::std::option::Option::None,
+        enum_values: ::std::option::Option::None,
+        const_value: ::std::option::Option::None,
+        subschemas: ::std::option::Option::Some(
+            ::std::boxed::Box::new(::schemars::schema::SubschemaValidation {
+                all_of: ::std::option::Option::None,
+                any_of: ::std::option::Option::None,
+                one_of: ::std::option::Option::Some(
+                    <::std::vec::Vec<
+                        _,
+                    > as ::std::convert::From<
                         _,
                     >>::from([
-                        schemars::schema::Schema::Object(schemars::schema::SchemaObject {
-                            metadata: Some(
-                                Box::new(schemars::schema::Metadata {
-                                    id: None,
-                                    title: Some(String::from("internal")),
-                                    description: None,
-                                    default: None,
+                        ::schemars::schema::Schema::Object(::schemars::schema::SchemaObject {
+                            metadata: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::Metadata {
+                                    id: ::std::option::Option::None,
+                                    title: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from("internal"),
+                                    ),
+                                    description: ::std::option::Option::None,
+                                    default: ::std::option::Option::None,
                                     deprecated: false,
                                     read_only: false,
                                     write_only: false,
-                                    examples: <std::vec::Vec<_>>::new(),
+                                    examples: <::std::vec::Vec<_>>::new(),
                                 }),
                             ),
-                            instance_type: Some(
-                                schemars::schema::SingleOrVec::Single(
-                                    Box::new(schemars::schema::InstanceType::String),
+                            instance_type: ::std::option::Option::Some(
+                                ::schemars::schema::SingleOrVec::Single(
+                                    ::std::boxed::Box::new(
+                                        ::schemars::schema::InstanceType::String,
+                                    ),
                                 ),
                             ),
-                            format: None,
-                            enum_values: None,
-                            const_value: None,
-                            subschemas: None,
-                            number: None,
-                            string: Some(
-                                Box::new(schemars::schema::StringValidation {
-                                    max_length: None,
-                                    min_length: None,
-                                    pattern: Some(String::from("(^[iI][nN][tT]0$)")),
+                            format: ::std::option::Option::None,
+                            enum_values: ::std::option::Option::None,
+                            const_value: ::std::option::Option::None,
+                            subschemas: ::std::option::Option::None,
+                            number: ::std::option::Option::None,
+                            string: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::StringValidation {
+                                    max_length: ::std::option::Option::None,
+                                    min_length: ::std::option::Option::None,
+                                    pattern: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from("(^[iI][nN][tT]0$)"),
+                                    ),
                                 }),
                             ),
-                            array: None,
-                            object: None,
-                            reference: None,
-                            extensions: <std::collections::BTreeMap<_, _>>::new(),
+                            array: ::std::option::Option::None,
+                            object: ::std::option::Option::None,
+                            reference: ::std::option::Option::None,
+                            extensions: <::std::collections::BTreeMap<_, _>>::new(),
                         }),
-                        schemars::schema::Schema::Object(schemars::schema::SchemaObject {
-                            metadata: Some(
-                                Box::new(schemars::schema::Metadata {
-                                    id: None,
-                                    title: Some(String::from("rear")),
-                                    description: None,
-                                    default: None,
+                        ::schemars::schema::Schema::Object(::schemars::schema::SchemaObject {
+                            metadata: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::Metadata {
+                                    id: ::std::option::Option::None,
+                                    title: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from("rear"),
+                                    ),
+                                    description: ::std::option::Option::None,
+                                    default: ::std::option::Option::None,
                                     deprecated: false,
                                     read_only: false,
                                     write_only: false,
-                                    examples: <std::vec::Vec<_>>::new(),
+                                    examples: <::std::vec::Vec<_>>::new(),
                                 }),
                             ),
-                            instance_type: Some(
-                                schemars::schema::SingleOrVec::Single(
-                                    Box::new(schemars::schema::InstanceType::String),
+                            instance_type: ::std::option::Option::Some(
+                                ::schemars::schema::SingleOrVec::Single(
+                                    ::std::boxed::Box::new(
+                                        ::schemars::schema::InstanceType::String,
+                                    ),
                                 ),
                             ),
-                            format: None,
-                            enum_values: None,
-                            const_value: None,
-                            subschemas: None,
-                            number: None,
-                            string: Some(
-                                Box::new(schemars::schema::StringValidation {
-                                    max_length: None,
-                                    min_length: None,
-                                    pattern: Some(
-                                        String::from(
+                            format: ::std::option::Option::None,
+                            enum_values: ::std::option::Option::None,
+                            const_value: ::std::option::Option::None,
+                            subschemas: ::std::option::Option::None,
+                            number: ::std::option::Option::None,
+                            string: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::StringValidation {
+                                    max_length: ::std::option::Option::None,
+                                    min_length: ::std::option::Option::None,
+                                    pattern: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from(
                                             "(^[rR][eE][aA][rR](([0-9])|([1-2][0-9])|(3[0-1]))$)",
                                         ),
                                     ),
                                 }),
                             ),
-                            array: None,
-                            object: None,
-                            reference: None,
-                            extensions: <std::collections::BTreeMap<_, _>>::new(),
+                            array: ::std::option::Option::None,
+                            object: ::std::option::Option::None,
+                            reference: ::std::option::Option::None,
+                            extensions: <::std::collections::BTreeMap<_, _>>::new(),
                         }),
-                        schemars::schema::Schema::Object(schemars::schema::SchemaObject {
-                            metadata: Some(
-                                Box::new(schemars::schema::Metadata {
-                                    id: None,
-                                    title: Some(String::from("qsfp")),
-                                    description: None,
-                                    default: None,
+                        ::schemars::schema::Schema::Object(::schemars::schema::SchemaObject {
+                            metadata: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::Metadata {
+                                    id: ::std::option::Option::None,
+                                    title: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from("qsfp"),
+                                    ),
+                                    description: ::std::option::Option::None,
+                                    default: ::std::option::Option::None,
                                     deprecated: false,
                                     read_only: false,
                                     write_only: false,
-                                    examples: <std::vec::Vec<_>>::new(),
+                                    examples: <::std::vec::Vec<_>>::new(),
                                 }),
                             ),
-                            instance_type: Some(
-                                schemars::schema::SingleOrVec::Single(
-                                    Box::new(schemars::schema::InstanceType::String),
+                            instance_type: ::std::option::Option::Some(
+                                ::schemars::schema::SingleOrVec::Single(
+                                    ::std::boxed::Box::new(
+                                        ::schemars::schema::InstanceType::String,
+                                    ),
                                 ),
                             ),
-                            format: None,
-                            enum_values: None,
-                            const_value: None,
-                            subschemas: None,
-                            number: None,
-                            string: Some(
-                                Box::new(schemars::schema::StringValidation {
-                                    max_length: None,
-                                    min_length: None,
-                                    pattern: Some(
-                                        String::from(
+                            format: ::std::option::Option::None,
+                            enum_values: ::std::option::Option::None,
+                            const_value: ::std::option::Option::None,
+                            subschemas: ::std::option::Option::None,
+                            number: ::std::option::Option::None,
+                            string: ::std::option::Option::Some(
+                                ::std::boxed::Box::new(::schemars::schema::StringValidation {
+                                    max_length: ::std::option::Option::None,
+                                    min_length: ::std::option::Option::None,
+                                    pattern: ::std::option::Option::Some(
+                                        <::std::string::String as ::std::convert::From<
+                                            &str,
+                                        >>::from(
                                             "(^[qQ][sS][fF][pP](([0-9])|([1-2][0-9])|(3[0-1]))$)",
                                         ),
                                     ),
                                 }),
                             ),
-                            array: None,
-                            object: None,
-                            reference: None,
-                            extensions: <std::collections::BTreeMap<_, _>>::new(),
+                            array: ::std::option::Option::None,
+                            object: ::std::option::Option::None,
+                            reference: ::std::option::Option::None,
+                            extensions: <::std::collections::BTreeMap<_, _>>::new(),
                         }),
                     ]),
                 ),
-                not: None,
-                if_schema: None,
-                then_schema: None,
-                else_schema: None,
+                not: ::std::option::Option::None,
+                if_schema: ::std::option::Option::None,
+                then_schema: ::std::option::Option::None,
+                else_schema: ::std::option::Option::None,
             }),
         ),
-        number: None,
-        string: None,
-        array: None,
-        object: None,
-        reference: None,
-        extensions: <std::collections::BTreeMap<
+        number: ::std::option::Option::None,
+        string: ::std::option::Option::None,
+        array: ::std::option::Option::None,
+        object: ::std::option::Option::None,
+        reference: ::std::option::Option::None,
+        extensions: <::std::collections::BTreeMap<
+            _,
             _,
+        > as ::std::convert::From<
             _,
         >>::from([
-            (String::from("example"), serde_json::Value::String(String::from("qsfp0"))),
+            (
+                <::std::string::String as ::std::convert::From<&str>>::from("example"),
+                ::serde_json::Value::String(
+                    <::std::string::String as ::std::convert::From<&str>>::from("qsfp0"),
+                ),
+            ),
         ]),
     });
 }
@@ -0,0 +1,371 @@
+pub mod constructors {
+    pub const DATES: [::time::Date; 4usize] = [
+        const {
+            match ::time::Date::from_calendar_date(
+                -9999i32,
+                ::time::Month::January,
+                1u8,
+            ) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::Date::from_calendar_date(
+                9999i32,
+                ::time::Month::December,
+                31u8,
+            ) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::Date::from_calendar_date(
+                1970i32,
+                ::time::Month::January,
+                1u8,
+            ) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::Date::from_calendar_date(
+                2000i32,
+                ::time::Month::February,
+                29u8,
+            ) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+    ];
+    pub fn dates() -> [::time::Date; 4usize] {
+        [
+            ::time::Date::from_calendar_date(-9999i32, ::time::Month::January, 1u8)
+                .unwrap(),
+            ::time::Date::from_calendar_date(9999i32, ::time::Month::December, 31u8)
+                .unwrap(),
+            ::time::Date::from_calendar_date(1970i32, ::time::Month::January, 1u8)
+                .unwrap(),
+            ::time::Date::from_calendar_date(2000i32, ::time::Month::February, 29u8)
+                .unwrap(),
+        ]
+    }
+    pub const TIMES: [::time::Time; 3usize] = [
+        const {
+            match ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::Time::from_hms_nano(23u8, 59u8, 59u8, 999999999u32) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+    ];
+    pub fn times() -> [::time::Time; 3usize] {
+        [
+            ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32).unwrap(),
+            ::time::Time::from_hms_nano(23u8, 59u8, 59u8, 999999999u32).unwrap(),
+            ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32).unwrap(),
+        ]
+    }
+    pub const OFFSETS: [::time::UtcOffset; 2usize] = [
+        const {
+            match ::time::UtcOffset::from_hms(5i8, 45i8, 0i8) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+        const {
+            match ::time::UtcOffset::from_hms(-23i8, -59i8, -59i8) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(),
+            }
+        },
+    ];
+    pub fn offsets() -> [::time::UtcOffset; 2usize] {
+        [
+            ::time::UtcOffset::from_hms(5i8, 45i8, 0i8).unwrap(),
+            ::time::UtcOffset::from_hms(-23i8, -59i8, -59i8).unwrap(),
+        ]
+    }
+    pub const DATE_TIMES: [::time::PrimitiveDateTime; 4usize] = [
+        ::time::PrimitiveDateTime::new(
+            const {
+                match ::time::Date::from_calendar_date(
+                    -9999i32,
+                    ::time::Month::January,
+                    1u8,
+                ) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+            const {
+                match ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+        ::time::PrimitiveDateTime::new(
+            const {
+                match ::time::Date::from_calendar_date(
+                    9999i32,
+                    ::time::Month::December,
+                    31u8,
+                ) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+            const {
+                match ::time::Time::from_hms_nano(23u8, 59u8, 59u8, 999999999u32) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+        ::time::PrimitiveDateTime::new(
+            const {
+                match ::time::Date::from_calendar_date(
+                    1970i32,
+                    ::time::Month::January,
+                    1u8,
+                ) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+            const {
+                match ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+        ::time::PrimitiveDateTime::new(
+            const {
+                match ::time::Date::from_calendar_date(
+                    2000i32,
+                    ::time::Month::February,
+                    29u8,
+                ) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+            const {
+                match ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+    ];
+    pub fn date_times() -> [::time::PrimitiveDateTime; 4usize] {
+        [
+            ::time::PrimitiveDateTime::new(
+                ::time::Date::from_calendar_date(-9999i32, ::time::Month::January, 1u8)
+                    .unwrap(),
+                ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32).unwrap(),
+            ),
+            ::time::PrimitiveDateTime::new(
+                ::time::Date::from_calendar_date(9999i32, ::time::Month::December, 31u8)
+                    .unwrap(),
+                ::time::Time::from_hms_nano(23u8, 59u8, 59u8, 999999999u32).unwrap(),
+            ),
+            ::time::PrimitiveDateTime::new(
+                ::time::Date::from_calendar_date(1970i32, ::time::Month::January, 1u8)
+                    .unwrap(),
+                ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32).unwrap(),
+            ),
+            ::time::PrimitiveDateTime::new(
+                ::time::Date::from_calendar_date(2000i32, ::time::Month::February, 29u8)
+                    .unwrap(),
+                ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32).unwrap(),
+            ),
+        ]
+    }
+    pub const OFFSET_DATE_TIMES: [::time::OffsetDateTime; 2usize] = [
+        ::time::PrimitiveDateTime::assume_offset(
+            ::time::PrimitiveDateTime::new(
+                const {
+                    match ::time::Date::from_calendar_date(
+                        1970i32,
+                        ::time::Month::January,
+                        1u8,
+                    ) {
+                        ::std::result::Result::Ok(value) => value,
+                        ::std::result::Result::Err(_) => ::std::panic!(),
+                    }
+                },
+                const {
+                    match ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32) {
+                        ::std::result::Result::Ok(value) => value,
+                        ::std::result::Result::Err(_) => ::std::panic!(),
+                    }
+                },
+            ),
+            const {
+                match ::time::UtcOffset::from_hms(5i8, 45i8, 0i8) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+        ::time::PrimitiveDateTime::assume_offset(
+            ::time::PrimitiveDateTime::new(
+                const {
+                    match ::time::Date::from_calendar_date(
+                        2000i32,
+                        ::time::Month::February,
+                        29u8,
+                    ) {
+                        ::std::result::Result::Ok(value) => value,
+                        ::std::result::Result::Err(_) => ::std::panic!(),
+                    }
+                },
+                const {
+                    match ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32) {
+                        ::std::result::Result::Ok(value) => value,
+                        ::std::result::Result::Err(_) => ::std::panic!(),
+                    }
+                },
+            ),
+            const {
+                match ::time::UtcOffset::from_hms(-23i8, -59i8, -59i8) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) => ::std::panic!(),
+                }
+            },
+        ),
+    ];
+    pub fn offset_date_times() -> [::time::OffsetDateTime; 2usize] {
+        [
+            ::time::PrimitiveDateTime::assume_offset(
+                ::time::PrimitiveDateTime::new(
+                    ::time::Date::from_calendar_date(
+                            1970i32,
+                            ::time::Month::January,
+                            1u8,
+                        )
+                        .unwrap(),
+                    ::time::Time::from_hms_nano(12u8, 34u8, 56u8, 789000000u32).unwrap(),
+                ),
+                ::time::UtcOffset::from_hms(5i8, 45i8, 0i8).unwrap(),
+            ),
+            ::time::PrimitiveDateTime::assume_offset(
+                ::time::PrimitiveDateTime::new(
+                    ::time::Date::from_calendar_date(
+                            2000i32,
+                            ::time::Month::February,
+                            29u8,
+                        )
+                        .unwrap(),
+                    ::time::Time::from_hms_nano(0u8, 0u8, 0u8, 0u32).unwrap(),
+                ),
+                ::time::UtcOffset::from_hms(-23i8, -59i8, -59i8).unwrap(),
+            ),
+        ]
+    }
+    pub const DURATIONS: [::time::Duration; 3usize] = [
+        ::time::Duration::new(-9223372036854775808i64, -999999999i32),
+        ::time::Duration::new(9223372036854775807i64, 999999999i32),
+        ::time::Duration::new(-2i64, -500000000i32),
+    ];
+    pub fn durations() -> [::time::Duration; 3usize] {
+        [
+            ::time::Duration::new(-9223372036854775808i64, -999999999i32),
+            ::time::Duration::new(9223372036854775807i64, 999999999i32),
+            ::time::Duration::new(-2i64, -500000000i32),
+        ]
+    }
+}
+pub mod macros {
+    pub const DATES: [::time::Date; 4usize] = [
+        ::time::macros::date!(- 9999 - 01 - 01),
+        ::time::macros::date!(9999 - 12 - 31),
+        ::time::macros::date!(1970 - 01 - 01),
+        ::time::macros::date!(2000 - 02 - 29),
+    ];
+    pub fn dates() -> [::time::Date; 4usize] {
+        [
+            ::time::macros::date!(- 9999 - 01 - 01),
+            ::time::macros::date!(9999 - 12 - 31),
+            ::time::macros::date!(1970 - 01 - 01),
+            ::time::macros::date!(2000 - 02 - 29),
+        ]
+    }
+    pub const TIMES: [::time::Time; 3usize] = [
+        ::time::macros::time!(0 : 00 : 00),
+        ::time::macros::time!(23 : 59 : 59.999999999),
+        ::time::macros::time!(12 : 34 : 56.789000000),
+    ];
+    pub fn times() -> [::time::Time; 3usize] {
+        [
+            ::time::macros::time!(0 : 00 : 00),
+            ::time::macros::time!(23 : 59 : 59.999999999),
+            ::time::macros::time!(12 : 34 : 56.789000000),
+        ]
+    }
+    pub const OFFSETS: [::time::UtcOffset; 2usize] = [
+        ::time::macros::offset!(+ 05 : 45 : 00),
+        ::time::macros::offset!(- 23 : 59 : 59),
+    ];
+    pub fn offsets() -> [::time::UtcOffset; 2usize] {
+        [
+            ::time::macros::offset!(+ 05 : 45 : 00),
+            ::time::macros::offset!(- 23 : 59 : 59),
+        ]
+    }
+    pub const DATE_TIMES: [::time::PrimitiveDateTime; 4usize] = [
+        ::time::macros::datetime!(- 9999 - 01 - 01 0 : 00 : 00),
+        ::time::macros::datetime!(9999 - 12 - 31 23 : 59 : 59.999999999),
+        ::time::macros::datetime!(1970 - 01 - 01 12 : 34 : 56.789000000),
+        ::time::macros::datetime!(2000 - 02 - 29 0 : 00 : 00),
+    ];
+    pub fn date_times() -> [::time::PrimitiveDateTime; 4usize] {
+        [
+            ::time::macros::datetime!(- 9999 - 01 - 01 0 : 00 : 00),
+            ::time::macros::datetime!(9999 - 12 - 31 23 : 59 : 59.999999999),
+            ::time::macros::datetime!(1970 - 01 - 01 12 : 34 : 56.789000000),
+            ::time::macros::datetime!(2000 - 02 - 29 0 : 00 : 00),
+        ]
+    }
+    pub const OFFSET_DATE_TIMES: [::time::OffsetDateTime; 2usize] = [
+        ::time::macros::datetime!(1970 - 01 - 01 12 : 34 : 56.789000000 + 05 : 45 : 00),
+        ::time::macros::datetime!(2000 - 02 - 29 0 : 00 : 00 - 23 : 59 : 59),
+    ];
+    pub fn offset_date_times() -> [::time::OffsetDateTime; 2usize] {
+        [
+            ::time::macros::datetime!(
+                1970 - 01 - 01 12 : 34 : 56.789000000 + 05 : 45 : 00
+            ),
+            ::time::macros::datetime!(2000 - 02 - 29 0 : 00 : 00 - 23 : 59 : 59),
+        ]
+    }
+    pub const DURATIONS: [::time::Duration; 3usize] = [
+        ::time::Duration::new(-9223372036854775808i64, -999999999i32),
+        ::time::Duration::new(9223372036854775807i64, 999999999i32),
+        ::time::Duration::new(-2i64, -500000000i32),
+    ];
+    pub fn durations() -> [::time::Duration; 3usize] {
+        [
+            ::time::Duration::new(-9223372036854775808i64, -999999999i32),
+            ::time::Duration::new(9223372036854775807i64, 999999999i32),
+            ::time::Duration::new(-2i64, -500000000i32),
+        ]
+    }
+}
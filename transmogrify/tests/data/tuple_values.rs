@@ -0,0 +1,33 @@
+pub const SINGLES: [(bool,); 2usize] = [(true,), (false,)];
+pub fn singles() -> [(bool,); 2usize] {
+    [(true,), (false,)]
+}
+pub fn pairs() -> [(u32, ::std::string::String); 1usize] {
+    [(1u32, <::std::string::String as ::std::convert::From<&str>>::from("s"))]
+}
+pub const PAIRS: [(u32, &str); 1usize] = [(1u32, "s")];
+pub const TWELVES: [crate::Twelve; 1usize] = [
+    (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8),
+];
+pub fn twelves() -> [crate::Twelve; 1usize] {
+    [(1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8)]
+}
+pub const WORDS: [[u16; 2]; 1usize] = [[1u16, 65535u16]];
+pub fn words() -> [[u16; 2]; 1usize] {
+    [[1u16, 65535u16]]
+}
+pub const BYTES: [[u8; 4]; 2usize] = [
+    [0u8, 34u8, 92u8, 255u8],
+    [97u8, 98u8, 99u8, 100u8],
+];
+pub fn bytes() -> [[u8; 4]; 2usize] {
+    [*b"\0\"\\\xFF", *b"abcd"]
+}
+pub const EMPTY: [[u8; 0]; 1usize] = [[]];
+pub fn empty() -> [[u8; 0]; 1usize] {
+    [*b""]
+}
+pub const NESTED: [[[u8; 1]; 2]; 1usize] = [[[1u8], [2u8]]];
+pub fn nested() -> [[[u8; 1]; 2]; 1usize] {
+    [[*b"\x01", *b"\x02"]]
+}
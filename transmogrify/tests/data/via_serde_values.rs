@@ -0,0 +1,10 @@
+pub fn tables() -> [::std::vec::Vec<(u32, ::std::string::String)>; 3usize] {
+    [
+        ::serde_json::from_str(r#"[[1,"one"],[2,"two"]]"#).unwrap(),
+        ::serde_json::from_str(r##"[[0,"\"#quoted#\""]]"##).unwrap(),
+        ::serde_json::from_str(r#"[]"#).unwrap(),
+    ]
+}
+pub fn derived() -> [crate::TestVia; 1usize] {
+    [::serde_json::from_str(r#"{"values":{"1":"a","2":"b"}}"#).unwrap()]
+}
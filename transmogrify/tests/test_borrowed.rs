@@ -0,0 +1,89 @@
+//! `&str`, `&[T]`, and `Cow` must round-trip. A `Cow` is emitted
+//! as owned unless the context asks for borrowed output, in which case it
+//! must be a `'static` borrow whichever variant it came from.
+
+#[macro_use]
+mod common;
+
+use std::borrow::Cow;
+
+use common::{assert_output, emit_const, emit_fn};
+use transmogrify::Context;
+
+compiled!("borrowed_values");
+
+fn strs() -> [&'static str; 2] {
+    ["s", "\"escaped\"\n"]
+}
+
+fn slices() -> [&'static [u32]; 2] {
+    [&[1, 2], &[]]
+}
+
+fn cows() -> [Cow<'static, str>; 2] {
+    [Cow::Borrowed("a"), Cow::Owned("b".to_string())]
+}
+
+fn byte_cows() -> [Cow<'static, [u8]>; 2] {
+    [Cow::Borrowed(&[1, 2]), Cow::Owned(vec![3])]
+}
+
+#[test]
+fn test_borrowed_output() {
+    let cx = Context::default();
+    let borrowed = Context::new().with_borrowed_cows(true);
+    let items = [
+        emit_fn("strs", syn::parse_quote! { &'static str }, &strs(), &cx),
+        emit_fn(
+            "slices",
+            syn::parse_quote! { &'static [u32] },
+            &slices(),
+            &cx,
+        ),
+        emit_fn(
+            "cows",
+            syn::parse_quote! { ::std::borrow::Cow<'static, str> },
+            &cows(),
+            &cx,
+        ),
+        emit_fn(
+            "borrowed_cows",
+            syn::parse_quote! { ::std::borrow::Cow<'static, str> },
+            &cows(),
+            &borrowed,
+        ),
+        emit_fn(
+            "byte_cows",
+            syn::parse_quote! { ::std::borrow::Cow<'static, [u8]> },
+            &byte_cows(),
+            &borrowed,
+        ),
+        emit_const(
+            "cows",
+            syn::parse_quote! { ::std::borrow::Cow<'static, str> },
+            &cows(),
+            &cx,
+        ),
+    ];
+    assert_output("borrowed_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_borrowed_roundtrip() {
+    assert_eq!(compiled::strs(), strs());
+    assert_eq!(compiled::slices(), slices());
+    assert_eq!(compiled::cows(), cows());
+    assert_eq!(compiled::COWS, cows());
+    assert_eq!(compiled::borrowed_cows(), cows());
+    assert_eq!(compiled::byte_cows(), byte_cows());
+
+    assert!(compiled::cows()
+        .iter()
+        .all(|cow| matches!(cow, Cow::Owned(_))));
+    assert!(compiled::borrowed_cows()
+        .iter()
+        .all(|cow| matches!(cow, Cow::Borrowed(_))));
+    assert!(compiled::byte_cows()
+        .iter()
+        .all(|cow| matches!(cow, Cow::Borrowed(_))));
+}
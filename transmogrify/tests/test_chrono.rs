@@ -0,0 +1,207 @@
+//! Every chrono type has a const form as well as a runtime form, and both
+//! must reproduce the value exactly: leap seconds, the extremes of each range,
+//! and offsets that aren't whole hours. `DateTime` values are compared by their
+//! parts since `==` ignores the offset.
+
+#[macro_use]
+mod common;
+
+use chrono::{
+    DateTime, FixedOffset, Local, Month, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    Utc, Weekday,
+};
+use common::{assert_output, emit_fn_and_const};
+use transmogrify::Context;
+
+compiled!("chrono_values");
+
+fn dates() -> Vec<NaiveDate> {
+    vec![
+        NaiveDate::MIN,
+        NaiveDate::MAX,
+        NaiveDate::from_ymd_opt(2000, 2, 29).unwrap(),
+    ]
+}
+
+fn times() -> Vec<NaiveTime> {
+    vec![
+        NaiveTime::MIN,
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap(),
+    ]
+}
+
+fn date_times() -> Vec<NaiveDateTime> {
+    dates()
+        .into_iter()
+        .zip(times().into_iter().cycle())
+        .map(|(date, time)| date.and_time(time))
+        .collect()
+}
+
+fn offsets() -> Vec<FixedOffset> {
+    vec![
+        FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap(),
+        FixedOffset::west_opt(86_399).unwrap(),
+    ]
+}
+
+fn utc_date_times() -> Vec<DateTime<Utc>> {
+    vec![
+        DateTime::<Utc>::MIN_UTC,
+        DateTime::<Utc>::from_timestamp(1_000_000_000, 42).unwrap(),
+    ]
+}
+
+fn fixed_date_times() -> Vec<DateTime<FixedOffset>> {
+    let naive = DateTime::<Utc>::from_timestamp(1_000_000_000, 42)
+        .unwrap()
+        .naive_utc();
+    offsets()
+        .into_iter()
+        .map(|offset| DateTime::from_naive_utc_and_offset(naive, offset))
+        .collect()
+}
+
+/// Built from a fixed offset so that the output doesn't depend on the time
+/// zone where the tests run.
+fn local_date_times() -> Vec<DateTime<Local>> {
+    fixed_date_times()
+        .into_iter()
+        .map(|value| DateTime::from_naive_utc_and_offset(value.naive_utc(), *value.offset()))
+        .collect()
+}
+
+fn deltas() -> Vec<TimeDelta> {
+    vec![
+        TimeDelta::MIN,
+        TimeDelta::MAX,
+        TimeDelta::new(-2, 500_000_000).unwrap(),
+    ]
+}
+
+fn weekdays() -> Vec<Weekday> {
+    vec![Weekday::Mon, Weekday::Sun]
+}
+
+fn months() -> Vec<Month> {
+    vec![Month::January, Month::December]
+}
+
+#[test]
+fn test_chrono_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn_and_const(
+            "dates",
+            syn::parse_quote! { ::chrono::NaiveDate },
+            &dates(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "times",
+            syn::parse_quote! { ::chrono::NaiveTime },
+            &times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "date_times",
+            syn::parse_quote! { ::chrono::NaiveDateTime },
+            &date_times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "offsets",
+            syn::parse_quote! { ::chrono::FixedOffset },
+            &offsets(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "utc_date_times",
+            syn::parse_quote! { ::chrono::DateTime<::chrono::Utc> },
+            &utc_date_times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "fixed_date_times",
+            syn::parse_quote! { ::chrono::DateTime<::chrono::FixedOffset> },
+            &fixed_date_times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "local_date_times",
+            syn::parse_quote! { ::chrono::DateTime<::chrono::Local> },
+            &local_date_times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "deltas",
+            syn::parse_quote! { ::chrono::TimeDelta },
+            &deltas(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "weekdays",
+            syn::parse_quote! { ::chrono::Weekday },
+            &weekdays(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "months",
+            syn::parse_quote! { ::chrono::Month },
+            &months(),
+            &cx,
+        ),
+    ];
+    assert_output("chrono_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_chrono_roundtrip() {
+    assert_eq!(compiled::DATES.to_vec(), dates());
+    assert_eq!(compiled::dates().to_vec(), dates());
+    assert_eq!(compiled::TIMES.to_vec(), times());
+    assert_eq!(compiled::times().to_vec(), times());
+    assert_eq!(compiled::DATE_TIMES.to_vec(), date_times());
+    assert_eq!(compiled::date_times().to_vec(), date_times());
+    assert_eq!(compiled::OFFSETS.to_vec(), offsets());
+    assert_eq!(compiled::offsets().to_vec(), offsets());
+    assert_eq!(compiled::UTC_DATE_TIMES.to_vec(), utc_date_times());
+    assert_eq!(compiled::utc_date_times().to_vec(), utc_date_times());
+    assert_eq!(compiled::DELTAS.to_vec(), deltas());
+    assert_eq!(compiled::deltas().to_vec(), deltas());
+    assert_eq!(compiled::WEEKDAYS.to_vec(), weekdays());
+    assert_eq!(compiled::weekdays().to_vec(), weekdays());
+    assert_eq!(compiled::MONTHS.to_vec(), months());
+    assert_eq!(compiled::months().to_vec(), months());
+
+    // `DateTime` equality considers only the instant, not the offset.
+    let parts = |values: &[DateTime<FixedOffset>]| {
+        values
+            .iter()
+            .map(|value| (value.naive_utc(), value.offset().fix()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        parts(&compiled::FIXED_DATE_TIMES),
+        parts(&fixed_date_times())
+    );
+    assert_eq!(
+        parts(&compiled::fixed_date_times()),
+        parts(&fixed_date_times())
+    );
+    let parts = |values: &[DateTime<Local>]| {
+        values
+            .iter()
+            .map(|value| (value.naive_utc(), value.offset().fix()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        parts(&compiled::LOCAL_DATE_TIMES),
+        parts(&local_date_times())
+    );
+    assert_eq!(
+        parts(&compiled::local_date_times()),
+        parts(&local_date_times())
+    );
+}
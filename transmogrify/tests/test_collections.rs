@@ -0,0 +1,109 @@
+//! The remaining std collections, and the boxed and reference-counted forms of
+//! slices and `str`, must round-trip, empty or not. A `BinaryHeap` has no
+//! equality, so its values are compared in sorted order.
+
+#[macro_use]
+mod common;
+
+use std::{
+    collections::{BinaryHeap, LinkedList, VecDeque},
+    rc::Rc,
+    sync::Arc,
+};
+
+use common::{assert_output, emit_fn};
+use transmogrify::Context;
+
+compiled!("collection_values");
+
+fn deques() -> [VecDeque<u8>; 2] {
+    [VecDeque::from([1, 2]), VecDeque::new()]
+}
+
+fn heaps() -> [BinaryHeap<u8>; 2] {
+    [BinaryHeap::from([2, 3, 1]), BinaryHeap::new()]
+}
+
+fn lists() -> [LinkedList<u8>; 2] {
+    [LinkedList::from([1, 2]), LinkedList::new()]
+}
+
+fn boxed_slices() -> [Box<[u8]>; 2] {
+    [Box::from([1, 2]), Box::default()]
+}
+
+fn boxed_strs() -> [Box<str>; 2] {
+    [Box::from("s"), Box::from("")]
+}
+
+fn rc_strs() -> [Rc<str>; 1] {
+    [Rc::from("s")]
+}
+
+fn arc_strs() -> [Arc<str>; 1] {
+    [Arc::from("\"s\"")]
+}
+
+#[test]
+fn test_collection_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn(
+            "deques",
+            syn::parse_quote! { ::std::collections::VecDeque<u8> },
+            &deques(),
+            &cx,
+        ),
+        emit_fn(
+            "heaps",
+            syn::parse_quote! { ::std::collections::BinaryHeap<u8> },
+            &heaps(),
+            &cx,
+        ),
+        emit_fn(
+            "lists",
+            syn::parse_quote! { ::std::collections::LinkedList<u8> },
+            &lists(),
+            &cx,
+        ),
+        emit_fn(
+            "boxed_slices",
+            syn::parse_quote! { ::std::boxed::Box<[u8]> },
+            &boxed_slices(),
+            &cx,
+        ),
+        emit_fn(
+            "boxed_strs",
+            syn::parse_quote! { ::std::boxed::Box<str> },
+            &boxed_strs(),
+            &cx,
+        ),
+        emit_fn(
+            "rc_strs",
+            syn::parse_quote! { ::std::rc::Rc<str> },
+            &rc_strs(),
+            &cx,
+        ),
+        emit_fn(
+            "arc_strs",
+            syn::parse_quote! { ::std::sync::Arc<str> },
+            &arc_strs(),
+            &cx,
+        ),
+    ];
+    assert_output("collection_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_collection_roundtrip() {
+    assert_eq!(compiled::deques(), deques());
+    assert_eq!(
+        compiled::heaps().map(BinaryHeap::into_sorted_vec),
+        heaps().map(BinaryHeap::into_sorted_vec),
+    );
+    assert_eq!(compiled::lists(), lists());
+    assert_eq!(compiled::boxed_slices(), boxed_slices());
+    assert_eq!(compiled::boxed_strs(), boxed_strs());
+    assert_eq!(compiled::rc_strs(), rc_strs());
+    assert_eq!(compiled::arc_strs(), arc_strs());
+}
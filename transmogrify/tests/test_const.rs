@@ -0,0 +1,105 @@
+//! `TransmogrifyConst` output must be accepted in a const initializer, with
+//! owned types in their borrowed forms, and must evaluate to the original
+//! value. Values with no const form are errors rather than code.
+
+#[macro_use]
+mod common;
+
+use std::{
+    collections::BTreeMap,
+    num::{NonZeroU16, NonZeroU8},
+};
+
+use common::{assert_output, emit_const};
+use transmogrify::{Context, TransmogrifyConst};
+
+compiled!("const_values");
+
+#[derive(Debug, PartialEq, TransmogrifyConst)]
+#[transmogrify(prefix = crate)]
+pub struct TestConst {
+    pub name: &'static str,
+    pub values: &'static [Option<NonZeroU8>],
+    #[transmogrify(default = 0)]
+    pub count: usize,
+}
+
+fn strings() -> Vec<Vec<String>> {
+    vec![vec!["a".to_string(), "b".to_string()], Vec::new()]
+}
+
+fn boxes() -> [Box<u8>; 1] {
+    [Box::new(1)]
+}
+
+fn options() -> Vec<Option<NonZeroU16>> {
+    vec![Some(NonZeroU16::MAX), None]
+}
+
+fn maps() -> Vec<BTreeMap<u8, u8>> {
+    vec![BTreeMap::new()]
+}
+
+fn derived() -> Vec<TestConst> {
+    vec![TestConst {
+        name: "n",
+        values: &[None, Some(NonZeroU8::MIN)],
+        count: 3,
+    }]
+}
+
+#[test]
+fn test_const_output() {
+    let cx = Context::default();
+    let items = [
+        emit_const("strings", syn::parse_quote! { &[&str] }, &strings(), &cx),
+        emit_const("boxes", syn::parse_quote! { &u8 }, &boxes(), &cx),
+        emit_const(
+            "options",
+            syn::parse_quote! { ::std::option::Option<::std::num::NonZeroU16> },
+            &options(),
+            &cx,
+        ),
+        emit_const(
+            "maps",
+            syn::parse_quote! { ::std::collections::BTreeMap<u8, u8> },
+            &maps(),
+            &cx,
+        ),
+        emit_const(
+            "derived",
+            syn::parse_quote! { crate::TestConst },
+            &derived(),
+            &cx,
+        ),
+    ];
+    assert_output("const_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_const_roundtrip() {
+    let borrowed = strings();
+    let borrowed = borrowed
+        .iter()
+        .map(|strings| strings.iter().map(String::as_str).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(compiled::STRINGS.to_vec(), borrowed);
+    assert_eq!(
+        compiled::BOXES.to_vec(),
+        boxes().iter().map(|b| &**b).collect::<Vec<_>>()
+    );
+    assert_eq!(compiled::OPTIONS.to_vec(), options());
+    assert_eq!(compiled::MAPS.to_vec(), maps());
+
+    // The field with a replacement takes that value instead.
+    let expected = derived()
+        .into_iter()
+        .map(|value| TestConst { count: 0, ..value })
+        .collect::<Vec<_>>();
+    assert_eq!(compiled::DERIVED.into_iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_const_error() {
+    assert!(BTreeMap::from([(1u8, 2u8)]).transmogrify_const().is_err());
+}
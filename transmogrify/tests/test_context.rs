@@ -0,0 +1,101 @@
+//! A `Context` can redirect the paths in the output, e.g. to re-exports for a
+//! consumer that doesn't depend on `std` or `chrono` by those names, or to the
+//! location of a `$crate`-prefixed type. Here the output may only reach those
+//! crates through `reexports`, and `my_crate`, which a derive names as its
+//! prefix, is this crate.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_fn};
+use transmogrify::{Context, Transmogrify};
+
+compiled!("context_values");
+
+mod reexports {
+    pub use ::chrono;
+    pub use ::std;
+}
+
+mod inner {
+    use transmogrify::Transmogrify;
+
+    #[derive(Debug, PartialEq, Transmogrify)]
+    #[transmogrify(prefix = $crate)]
+    pub struct TestStructRoot {
+        pub values: Vec<u32>,
+        pub time: chrono::DateTime<chrono::Utc>,
+        pub name: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Transmogrify)]
+    #[transmogrify(prefix = my_crate::inner)]
+    pub struct TestStructPrefixed {
+        pub root: TestStructRoot,
+    }
+}
+
+fn context() -> Context {
+    Context::new()
+        .with_crate_root(quote::quote! { crate::inner })
+        .with_crate_path("std", quote::quote! { crate::reexports::std })
+        .with_crate_path("chrono", quote::quote! { crate::reexports::chrono })
+        .with_crate_path("my_crate", quote::quote! { crate })
+}
+
+fn values() -> Vec<inner::TestStructRoot> {
+    vec![
+        inner::TestStructRoot {
+            values: vec![1, 2],
+            time: chrono::DateTime::<chrono::Utc>::from_timestamp(0, 0).unwrap(),
+            name: Some("a".to_string()),
+        },
+        inner::TestStructRoot {
+            values: Vec::new(),
+            time: chrono::DateTime::<chrono::Utc>::MAX_UTC,
+            name: None,
+        },
+    ]
+}
+
+fn prefixed() -> [inner::TestStructPrefixed; 1] {
+    [inner::TestStructPrefixed {
+        root: values().swap_remove(0),
+    }]
+}
+
+#[test]
+fn test_context_output() {
+    let cx = context();
+    let output = values()[0].transmogrify_with(&cx).to_string();
+    let remapped = output
+        .replace("crate :: reexports :: std", "")
+        .replace("crate :: reexports :: chrono", "");
+    assert!(
+        !remapped.contains("std") && !remapped.contains("chrono"),
+        "{}",
+        output
+    );
+
+    let items = [
+        emit_fn(
+            "values",
+            syn::parse_quote! { crate::inner::TestStructRoot },
+            &values(),
+            &cx,
+        ),
+        emit_fn(
+            "prefixed",
+            syn::parse_quote! { crate::inner::TestStructPrefixed },
+            &prefixed(),
+            &cx,
+        ),
+    ];
+    assert_output("context_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_context_roundtrip() {
+    assert_eq!(compiled::values().into_iter().collect::<Vec<_>>(), values());
+    assert_eq!(compiled::prefixed(), prefixed());
+}
@@ -0,0 +1,99 @@
+//! `transmogrify_dag` must produce the same value as plain emission while
+//! writing each large repeated value only once. Size is judged by the length
+//! of the code, so a long string or byte array counts as large even though it
+//! is a single token.
+
+#[macro_use]
+mod common;
+
+use std::collections::HashMap;
+
+use common::assert_output;
+use transmogrify::{transmogrify_dag, Context, Transmogrify};
+
+compiled!("dag_values");
+
+type Table = HashMap<String, (Vec<String>, Vec<Vec<String>>)>;
+
+fn table() -> Table {
+    let item = vec!["a".to_string(), "long".to_string(), "list".to_string()];
+    HashMap::from([
+        (
+            "x".to_string(),
+            (item.clone(), vec![item.clone(), item.clone()]),
+        ),
+        ("y".to_string(), (Vec::new(), vec![item])),
+    ])
+}
+
+fn strings() -> Vec<String> {
+    vec!["0123456789".repeat(8); 3]
+}
+
+fn bytes() -> Vec<[u8; 64]> {
+    vec![[0xa5; 64], [0xa5; 64], [0; 64]]
+}
+
+/// Small values aren't worth binding even if repeated.
+fn small() -> Vec<u8> {
+    vec![1, 1, 1]
+}
+
+/// Emit `value` with `transmogrify_dag` as the result of the function `name`.
+fn emit_dag<T: Transmogrify>(name: &str, ty: syn::Type, value: &T) -> proc_macro2::TokenStream {
+    let func = quote::format_ident!("{}", name);
+    let value = transmogrify_dag(value, &Context::default());
+    quote::quote! {
+        pub fn #func() -> #ty {
+            #value
+        }
+    }
+}
+
+#[test]
+fn test_dag_output() {
+    let items = [
+        emit_dag(
+            "table",
+            syn::parse_quote! {
+                ::std::collections::HashMap<
+                    ::std::string::String,
+                    (
+                        ::std::vec::Vec<::std::string::String>,
+                        ::std::vec::Vec<::std::vec::Vec<::std::string::String>>,
+                    ),
+                >
+            },
+            &table(),
+        ),
+        emit_dag(
+            "strings",
+            syn::parse_quote! { ::std::vec::Vec<::std::string::String> },
+            &strings(),
+        ),
+        emit_dag(
+            "bytes",
+            syn::parse_quote! { ::std::vec::Vec<[u8; 64]> },
+            &bytes(),
+        ),
+        emit_dag("small", syn::parse_quote! { ::std::vec::Vec<u8> }, &small()),
+    ];
+    assert_output("dag_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_dag_roundtrip() {
+    assert_eq!(compiled::table(), table());
+    assert_eq!(compiled::strings(), strings());
+    assert_eq!(compiled::bytes(), bytes());
+    assert_eq!(compiled::small(), small());
+}
+
+#[test]
+fn test_dag_long_literals() {
+    let output = transmogrify_dag(&strings(), &Context::default()).to_string();
+    assert_eq!(output.matches(&strings()[0]).count(), 1, "{}", output);
+
+    let output = transmogrify_dag(&bytes(), &Context::default()).to_string();
+    assert_eq!(output.matches("\\xA5").count(), 64, "{}", output);
+}
@@ -1,13 +1,23 @@
 use expectorate::assert_contents;
 use prettyplease::unparse;
-use transmogrify::Transmogrify;
+use transmogrify::{Context, Transmogrify};
 
-fn test_value<T: Transmogrify>(value: T, name: &'static str) {
-    let output = value.transmogrify();
-    println!("{}", output);
+/// Write the output as the body of a function returning `ty` so that the
+/// snapshot can be compiled and checked.
+fn test_value_fn<T: Transmogrify>(value: &T, ty: proc_macro2::TokenStream, name: &'static str) {
+    test_value_fn_with(value, ty, name, &Context::default())
+}
+
+fn test_value_fn_with<T: Transmogrify>(
+    value: &T,
+    ty: proc_macro2::TokenStream,
+    name: &'static str,
+    cx: &Context,
+) {
+    let output = value.transmogrify_with(cx);
     let file = syn::parse_quote! {
-        fn main() {
-            let _ = #output;
+        pub fn value() -> #ty {
+            #output
         }
     };
 
@@ -15,17 +25,21 @@ fn test_value<T: Transmogrify>(value: T, name: &'static str) {
     assert_contents(format!("tests/data/{}.rs", name), &actual)
 }
 
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestStructNamed {
+    pub a: String,
+    pub b: Vec<u32>,
+    pub time: chrono::DateTime<chrono::Utc>,
+    pub nz: std::num::NonZeroU8,
+}
+
+mod named {
+    include!("data/test_derive_struct.rs");
+}
+
 #[test]
 fn test_derive_struct() {
-    #[derive(Transmogrify)]
-    #[transmogrify(prefix = crate)]
-    pub struct TestStructNamed {
-        pub a: String,
-        pub b: Vec<u32>,
-        pub time: chrono::DateTime<chrono::Utc>,
-        pub nz: std::num::NonZeroU8,
-    }
-
     let value = TestStructNamed {
         a: "A".to_string(),
         b: vec![1, 2, 3],
@@ -33,5 +47,357 @@ fn test_derive_struct() {
         nz: 42.try_into().unwrap(),
     };
 
-    test_value(value, "test_derive_struct");
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructNamed },
+        "test_derive_struct",
+    );
+    assert_eq!(named::value(), value);
+}
+
+#[derive(Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestStructSkip {
+    pub a: String,
+    #[transmogrify(skip)]
+    pub cache: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+    #[transmogrify(default = Vec::new())]
+    pub scratch: Vec<std::cell::Cell<u8>>,
+}
+
+mod skip {
+    include!("data/test_derive_skip.rs");
+}
+
+#[test]
+fn test_derive_skip() {
+    let value = TestStructSkip {
+        a: "A".to_string(),
+        cache: std::sync::Arc::new(std::sync::Mutex::new(vec![1])),
+        scratch: vec![std::cell::Cell::new(1)],
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructSkip },
+        "test_derive_skip",
+    );
+
+    // Skipped fields take their default values.
+    let compiled = skip::value();
+    assert_eq!(compiled.a, value.a);
+    assert!(compiled.cache.lock().unwrap().is_empty());
+    assert!(compiled.scratch.is_empty());
+}
+
+pub mod foreign {
+    #[derive(Debug, PartialEq)]
+    pub struct Celsius(pub f64);
+}
+
+fn emit_celsius(value: &foreign::Celsius, cx: &Context) -> proc_macro2::TokenStream {
+    let degrees = value.0.transmogrify_with(cx);
+    quote::quote! { crate::foreign::Celsius(#degrees) }
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestStructWith {
+    pub name: String,
+    #[transmogrify(with = emit_celsius)]
+    pub temperature: foreign::Celsius,
+}
+
+mod with {
+    include!("data/test_derive_with.rs");
+}
+
+#[test]
+fn test_derive_with() {
+    let value = TestStructWith {
+        name: "kitchen".to_string(),
+        temperature: foreign::Celsius(21.5),
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructWith },
+        "test_derive_with",
+    );
+    assert_eq!(with::value(), value);
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestStructGeneric<T> {
+    pub inner: T,
+    pub items: Vec<T>,
+}
+
+mod generic {
+    include!("data/test_derive_generic.rs");
+}
+
+#[test]
+fn test_derive_generic() {
+    let value = TestStructGeneric {
+        inner: "A".to_string(),
+        items: vec!["B".to_string()],
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructGeneric<String> },
+        "test_derive_generic",
+    );
+    assert_eq!(generic::value(), value);
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate, constructor = TestStructPrivate::new)]
+pub struct TestStructPrivate {
+    name: String,
+    #[transmogrify(skip)]
+    len: usize,
+}
+
+impl TestStructPrivate {
+    pub fn new(name: String) -> Self {
+        let len = name.len();
+        Self { name, len }
+    }
+}
+
+mod constructor {
+    include!("data/test_derive_constructor.rs");
+}
+
+#[test]
+fn test_derive_constructor() {
+    let value = TestStructPrivate::new("A".to_string());
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructPrivate },
+        "test_derive_constructor",
+    );
+    assert_eq!(constructor::value(), value);
+}
+
+#[derive(Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestRangeRepr {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate, from = TestRangeRepr)]
+pub struct TestRange {
+    start: u32,
+    end: u32,
+}
+
+impl From<&TestRange> for TestRangeRepr {
+    fn from(value: &TestRange) -> Self {
+        Self {
+            start: value.start,
+            end: value.end,
+        }
+    }
+}
+
+impl From<TestRangeRepr> for TestRange {
+    fn from(value: TestRangeRepr) -> Self {
+        Self {
+            start: value.start,
+            end: value.end,
+        }
+    }
+}
+
+mod from {
+    include!("data/test_derive_from.rs");
+}
+
+#[test]
+fn test_derive_from() {
+    let value = TestRange { start: 1, end: 5 };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestRange },
+        "test_derive_from",
+    );
+    assert_eq!(from::value(), value);
+}
+
+mod inner {
+    use transmogrify::Transmogrify;
+
+    #[derive(Debug, PartialEq, Transmogrify)]
+    #[transmogrify(prefix = crate, rename = PublicName)]
+    pub enum RealName {
+        Plain(u32),
+        #[transmogrify(path = crate::Shortcut)]
+        Shortcut,
+    }
+}
+
+pub use inner::RealName as PublicName;
+pub use inner::RealName::Shortcut;
+
+mod rename {
+    include!("data/test_derive_rename.rs");
+}
+
+#[test]
+fn test_derive_rename() {
+    let value = vec![inner::RealName::Plain(7), inner::RealName::Shortcut];
+
+    test_value_fn(
+        &value,
+        quote::quote! { Vec<crate::PublicName> },
+        "test_derive_rename",
+    );
+    assert_eq!(rename::value(), value);
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(local)]
+pub struct TestStructLocal {
+    pub a: String,
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = $crate)]
+pub struct TestStructRoot {
+    pub local: TestStructLocal,
+}
+
+/// The crate root given to the context in `test_derive_local`.
+pub mod root {
+    pub use crate::TestStructRoot;
+}
+
+mod local {
+    // Types deriving with `local` are emitted unqualified.
+    use crate::TestStructLocal;
+
+    include!("data/test_derive_local.rs");
+}
+
+#[test]
+fn test_derive_local() {
+    let value = TestStructRoot {
+        local: TestStructLocal { a: "A".to_string() },
+    };
+
+    let cx = Context::new().with_crate_root(quote::quote! { crate::root });
+    test_value_fn_with(
+        &value,
+        quote::quote! { crate::TestStructRoot },
+        "test_derive_local",
+        &cx,
+    );
+    assert_eq!(local::value(), value);
+}
+
+#[derive(Debug, PartialEq, Transmogrify, serde::Serialize, serde::Deserialize)]
+#[transmogrify(via = "json")]
+pub struct TestStructVia {
+    table: Vec<(u32, String)>,
+}
+
+mod via {
+    include!("data/test_derive_via.rs");
+}
+
+#[test]
+fn test_derive_via() {
+    let value = TestStructVia {
+        table: vec![(1, "one".to_string()), (2, "two".to_string())],
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructVia },
+        "test_derive_via",
+    );
+    assert_eq!(via::value(), value);
+}
+
+#[derive(Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestPairRepr<T> {
+    pub first: T,
+    pub second: T,
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate, from = TestPairRepr<T>)]
+pub struct TestPair<T> {
+    first: T,
+    second: T,
+}
+
+impl<T: Clone> From<&TestPair<T>> for TestPairRepr<T> {
+    fn from(value: &TestPair<T>) -> Self {
+        Self {
+            first: value.first.clone(),
+            second: value.second.clone(),
+        }
+    }
+}
+
+impl<T> From<TestPairRepr<T>> for TestPair<T> {
+    fn from(value: TestPairRepr<T>) -> Self {
+        Self {
+            first: value.first,
+            second: value.second,
+        }
+    }
+}
+
+mod from_generic {
+    include!("data/test_derive_from_generic.rs");
+}
+
+#[test]
+fn test_derive_from_generic() {
+    let value = TestPair {
+        first: "a".to_string(),
+        second: "b".to_string(),
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestPair<String> },
+        "test_derive_from_generic",
+    );
+    assert_eq!(from_generic::value(), value);
+}
+
+#[derive(Debug, PartialEq, Transmogrify, serde::Serialize, serde::Deserialize)]
+#[transmogrify(via = "json")]
+pub struct TestStructViaGeneric<T> {
+    table: Vec<(T, String)>,
+}
+
+mod via_generic {
+    include!("data/test_derive_via_generic.rs");
+}
+
+#[test]
+fn test_derive_via_generic() {
+    let value = TestStructViaGeneric {
+        table: vec![(1u32, "one".to_string()), (2, "two".to_string())],
+    };
+
+    test_value_fn(
+        &value,
+        quote::quote! { crate::TestStructViaGeneric<u32> },
+        "test_derive_via_generic",
+    );
+    assert_eq!(via_generic::value(), value);
 }
@@ -0,0 +1,92 @@
+//! Floats must round-trip exactly, including NaN payloads, infinities, and
+//! negative zero. A handful of pseudo-random bit patterns supplement the
+//! special values; all are compared by their bits rather than with `==`.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_fn_and_const};
+use transmogrify::Context;
+
+compiled!("float_values");
+
+const COUNT: usize = 8;
+
+/// SplitMix64; a small generator is plenty for picking bit patterns.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+fn f64s() -> Vec<f64> {
+    let mut rng = Rng(64);
+    let special = [
+        f64::NAN,
+        -f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        0.0,
+        -0.0,
+        f64::MIN,
+        f64::MIN_POSITIVE,
+        0.1,
+    ];
+    let nans = [0x7ff0000000000001, 0xfff8000000000001, 1].map(f64::from_bits);
+    special
+        .into_iter()
+        .chain(nans)
+        .chain((0..COUNT).map(|_| f64::from_bits(rng.next())))
+        .collect()
+}
+
+fn f32s() -> Vec<f32> {
+    let mut rng = Rng(32);
+    let special = [
+        f32::NAN,
+        -f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        0.0,
+        -0.0,
+        f32::MAX,
+        f32::EPSILON,
+        0.1,
+    ];
+    let nans = [0x7f800001, 0xffc00001, 1].map(f32::from_bits);
+    special
+        .into_iter()
+        .chain(nans)
+        .chain((0..COUNT).map(|_| f32::from_bits(rng.next() as u32)))
+        .collect()
+}
+
+#[test]
+fn test_float_output() {
+    let cx = Context::default();
+    let f64s = emit_fn_and_const("f64s", syn::parse_quote! { f64 }, &f64s(), &cx);
+    let f32s = emit_fn_and_const("f32s", syn::parse_quote! { f32 }, &f32s(), &cx);
+    assert_output(
+        "float_values",
+        quote::quote! {
+            #f64s
+            #f32s
+        },
+    );
+}
+
+#[test]
+fn test_float_roundtrip() {
+    let bits = |values: &[f64]| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+    assert_eq!(bits(&compiled::F64S), bits(&f64s()));
+    assert_eq!(bits(&compiled::f64s()), bits(&f64s()));
+    let bits = |values: &[f32]| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+    assert_eq!(bits(&compiled::F32S), bits(&f32s()));
+    assert_eq!(bits(&compiled::f32s()), bits(&f32s()));
+}
@@ -0,0 +1,91 @@
+//! `HashMap` and `HashSet` must round-trip with the default hasher or any
+//! other that implements `Default`. Their iteration order varies from one map
+//! to the next, so the output must not: entries are sorted by emitted text.
+
+#[macro_use]
+mod common;
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, DefaultHasher},
+};
+
+use common::{assert_output, emit_fn};
+use transmogrify::{Context, Transmogrify};
+
+compiled!("hash_values");
+
+pub type Fixed = BuildHasherDefault<DefaultHasher>;
+
+fn maps() -> [HashMap<u8, bool>; 2] {
+    [
+        HashMap::from_iter((0..12).map(|i| (i, i % 2 == 0))),
+        HashMap::new(),
+    ]
+}
+
+fn fixed_maps() -> [HashMap<u8, u8, Fixed>; 2] {
+    [HashMap::from_iter([(1, 2), (3, 4)]), HashMap::default()]
+}
+
+fn sets() -> [HashSet<&'static str>; 2] {
+    [HashSet::from(["b", "a", "c"]), HashSet::new()]
+}
+
+fn fixed_sets() -> [HashSet<u8, Fixed>; 2] {
+    [HashSet::from_iter([2, 1]), HashSet::default()]
+}
+
+#[test]
+fn test_hash_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn(
+            "maps",
+            syn::parse_quote! { ::std::collections::HashMap<u8, bool> },
+            &maps(),
+            &cx,
+        ),
+        emit_fn(
+            "fixed_maps",
+            syn::parse_quote! { ::std::collections::HashMap<u8, u8, crate::Fixed> },
+            &fixed_maps(),
+            &cx,
+        ),
+        emit_fn(
+            "sets",
+            syn::parse_quote! { ::std::collections::HashSet<&'static str> },
+            &sets(),
+            &cx,
+        ),
+        emit_fn(
+            "fixed_sets",
+            syn::parse_quote! { ::std::collections::HashSet<u8, crate::Fixed> },
+            &fixed_sets(),
+            &cx,
+        ),
+    ];
+    assert_output("hash_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_hash_roundtrip() {
+    assert_eq!(compiled::maps(), maps());
+    assert_eq!(compiled::fixed_maps(), fixed_maps());
+    assert_eq!(compiled::sets(), sets());
+    assert_eq!(compiled::fixed_sets(), fixed_sets());
+}
+
+#[test]
+fn test_hash_order() {
+    // Each map has its own random state, so these iterate in different orders
+    // but must produce the same output.
+    let expected = maps()[0].transmogrify().to_string();
+    for _ in 0..8 {
+        assert_eq!(maps()[0].transmogrify().to_string(), expected);
+    }
+    let expected = sets()[0].transmogrify().to_string();
+    for _ in 0..8 {
+        assert_eq!(sets()[0].transmogrify().to_string(), expected);
+    }
+}
@@ -0,0 +1,115 @@
+//! `serde_json` values must round-trip, including numbers at the limits of
+//! each representation, and object keys must appear in the output in the
+//! order the map holds them. Above the context's threshold a value is
+//! embedded as a JSON string instead, which must round-trip just the same.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_fn};
+use serde_json::{json, Map, Number, Value};
+use transmogrify::{Context, Transmogrify};
+
+compiled!("json_values");
+
+fn values() -> Vec<Value> {
+    vec![
+        json!({
+            "null": null,
+            "bool": true,
+            "unsigned": u64::MAX,
+            "signed": i64::MIN,
+            "float": 0.1,
+            "string": "s",
+            "array": [1, [], {}],
+        }),
+        Value::Null,
+    ]
+}
+
+fn numbers() -> Vec<Number> {
+    vec![
+        Number::from(u64::MAX),
+        Number::from(i64::MIN),
+        Number::from_f64(-0.5).unwrap(),
+    ]
+}
+
+fn maps() -> Vec<Map<String, Value>> {
+    let Value::Object(map) = json!({ "b": 1, "a": [true] }) else {
+        unreachable!()
+    };
+    vec![map, Map::new()]
+}
+
+/// Values on either side of a threshold of 10.
+fn thresholded() -> [Value; 2] {
+    [
+        json!([1, 2]),
+        json!({ "key": ["a\"#b", { "nested": 1.5 }] }),
+    ]
+}
+
+#[test]
+fn test_json_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn(
+            "values",
+            syn::parse_quote! { ::serde_json::Value },
+            &values(),
+            &cx,
+        ),
+        emit_fn(
+            "numbers",
+            syn::parse_quote! { ::serde_json::Number },
+            &numbers(),
+            &cx,
+        ),
+        emit_fn(
+            "maps",
+            syn::parse_quote! {
+                ::serde_json::Map<::std::string::String, ::serde_json::Value>
+            },
+            &maps(),
+            &cx,
+        ),
+        emit_fn(
+            "thresholded",
+            syn::parse_quote! { ::serde_json::Value },
+            &thresholded(),
+            &Context::new().with_json_threshold(10),
+        ),
+    ];
+    assert_output("json_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_json_roundtrip() {
+    assert_eq!(compiled::values().to_vec(), values());
+    assert_eq!(compiled::numbers().to_vec(), numbers());
+    assert_eq!(compiled::maps().to_vec(), maps());
+    assert_eq!(compiled::thresholded(), thresholded());
+}
+
+#[test]
+fn test_json_threshold() {
+    let cx = Context::new().with_json_threshold(10);
+    let [small, large] = thresholded().map(|value| value.transmogrify_with(&cx).to_string());
+    assert!(!small.contains("from_str"), "{}", small);
+    assert!(large.contains("from_str"), "{}", large);
+}
+
+#[test]
+fn test_json_key_order() {
+    let value = &values()[0];
+    let Value::Object(map) = value else {
+        unreachable!()
+    };
+    let output = value.transmogrify().to_string();
+    let positions = map
+        .keys()
+        .map(|key| output.find(&format!("\"{}\"", key)).unwrap())
+        .collect::<Vec<_>>();
+    assert!(positions.is_sorted(), "keys out of order: {}", output);
+}
@@ -0,0 +1,116 @@
+//! Generated code must not depend on items in scope where it's used. Each test
+//! here compares generated output against a file in `tests/data`; those files
+//! are, in turn, compiled below in a `#![no_implicit_prelude]` module.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use expectorate::assert_contents;
+use prettyplease::unparse;
+use transmogrify::Transmogrify;
+
+#[allow(dead_code)]
+mod compiled {
+    #![no_implicit_prelude]
+
+    mod basic {
+        ::std::include!("data/prelude_basic.rs");
+    }
+    mod derive {
+        ::std::include!("data/prelude_derive.rs");
+    }
+    mod schema {
+        ::std::include!("data/test_schema.rs");
+    }
+}
+
+/// Emit each value as `let _: <type> = <value>;` where the type must also
+/// be written without reliance on the prelude.
+fn test_values(values: &[(&dyn Transmogrify, syn::Type)], name: &'static str) {
+    let lets = values.iter().map(|(value, ty)| {
+        let value = value.transmogrify();
+        quote::quote! {
+            let _: #ty = #value;
+        }
+    });
+    let file = syn::parse_quote! {
+        fn main() {
+            #( #lets )*
+        }
+    };
+
+    let actual = unparse(&file);
+    assert_contents(format!("tests/data/{}.rs", name), &actual)
+}
+
+#[test]
+fn test_prelude_basic() {
+    let map: BTreeMap<String, Vec<Option<Box<std::num::NonZeroU8>>>> = BTreeMap::from([(
+        "a".to_string(),
+        vec![Some(Box::new(1.try_into().unwrap())), None],
+    )]);
+
+    test_values(
+        &[
+            (
+                &map,
+                syn::parse_quote! {
+                    ::std::collections::BTreeMap<
+                        ::std::string::String,
+                        ::std::vec::Vec<
+                            ::std::option::Option<::std::boxed::Box<::std::num::NonZeroU8>>
+                        >,
+                    >
+                },
+            ),
+            (
+                &BTreeMap::<String, u8>::new(),
+                syn::parse_quote! { ::std::collections::BTreeMap<::std::string::String, u8> },
+            ),
+            (
+                &BTreeSet::from([true]),
+                syn::parse_quote! { ::std::collections::BTreeSet<bool> },
+            ),
+            (
+                &BTreeSet::<bool>::new(),
+                syn::parse_quote! { ::std::collections::BTreeSet<bool> },
+            ),
+            (&Vec::<u8>::new(), syn::parse_quote! { ::std::vec::Vec<u8> }),
+            (
+                &"s".to_string(),
+                syn::parse_quote! { ::std::string::String },
+            ),
+        ],
+        "prelude_basic",
+    );
+}
+
+#[derive(Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub enum TestEnum {
+    Named {
+        name: String,
+        #[transmogrify(skip)]
+        cache: Vec<u8>,
+    },
+    Unnamed(Option<String>),
+}
+
+#[test]
+fn test_prelude_derive() {
+    test_values(
+        &[
+            (
+                &TestEnum::Named {
+                    name: "a".to_string(),
+                    cache: vec![1],
+                },
+                syn::parse_quote! { crate::TestEnum },
+            ),
+            (
+                &TestEnum::Unnamed(None),
+                syn::parse_quote! { crate::TestEnum },
+            ),
+        ],
+        "prelude_derive",
+    );
+}
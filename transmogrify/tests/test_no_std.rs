@@ -0,0 +1,133 @@
+//! In `no_std` mode the output must name `core` and `alloc` rather than `std`,
+//! including in derived impls, so that it builds in a `#![no_std]` crate.
+
+#[macro_use]
+mod common;
+
+// Make `::alloc` available as it would be in a `#![no_std]` crate.
+extern crate alloc;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroI32,
+};
+
+use common::{assert_output, emit_fn};
+use transmogrify::{Context, Transmogrify};
+
+compiled!("no_std_values");
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate)]
+pub struct TestNoStd {
+    pub name: Option<String>,
+    #[transmogrify(skip)]
+    pub cache: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Transmogrify)]
+#[transmogrify(prefix = crate, from = TestNoStd)]
+pub struct TestNoStdFrom {
+    name: Option<String>,
+}
+
+impl From<&TestNoStdFrom> for TestNoStd {
+    fn from(value: &TestNoStdFrom) -> Self {
+        Self {
+            name: value.name.clone(),
+            cache: Vec::new(),
+        }
+    }
+}
+
+impl From<TestNoStd> for TestNoStdFrom {
+    fn from(value: TestNoStd) -> Self {
+        Self { name: value.name }
+    }
+}
+
+fn maps() -> Vec<BTreeMap<u8, Vec<Option<Box<NonZeroI32>>>>> {
+    vec![BTreeMap::from([(
+        1,
+        vec![Some(Box::new(NonZeroI32::MIN)), None],
+    )])]
+}
+
+fn sets() -> Vec<BTreeSet<String>> {
+    vec![BTreeSet::from(["s".to_string()])]
+}
+
+fn derived() -> Vec<TestNoStd> {
+    vec![TestNoStd {
+        name: Some("a".to_string()),
+        cache: Vec::new(),
+    }]
+}
+
+fn converted() -> Vec<TestNoStdFrom> {
+    vec![TestNoStdFrom { name: None }]
+}
+
+#[test]
+fn test_no_std_output() {
+    let cx = Context::new().with_no_std(true);
+    let outputs = [
+        maps()[0].transmogrify_with(&cx),
+        sets()[0].transmogrify_with(&cx),
+        derived()[0].transmogrify_with(&cx),
+        converted()[0].transmogrify_with(&cx),
+    ];
+    for output in outputs {
+        let output = output.to_string();
+        assert!(!output.contains("std"), "{}", output);
+    }
+
+    let items = [
+        emit_fn(
+            "maps",
+            syn::parse_quote! {
+                ::alloc::collections::BTreeMap<
+                    u8,
+                    ::alloc::vec::Vec<
+                        ::core::option::Option<::alloc::boxed::Box<::core::num::NonZeroI32>>,
+                    >,
+                >
+            },
+            &maps(),
+            &cx,
+        ),
+        emit_fn(
+            "sets",
+            syn::parse_quote! { ::alloc::collections::BTreeSet<::alloc::string::String> },
+            &sets(),
+            &cx,
+        ),
+        emit_fn(
+            "derived",
+            syn::parse_quote! { crate::TestNoStd },
+            &derived(),
+            &cx,
+        ),
+        emit_fn(
+            "converted",
+            syn::parse_quote! { crate::TestNoStdFrom },
+            &converted(),
+            &cx,
+        ),
+    ];
+    assert_output("no_std_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_no_std_roundtrip() {
+    assert_eq!(compiled::maps().to_vec(), maps());
+    assert_eq!(compiled::sets().to_vec(), sets());
+    assert_eq!(
+        compiled::derived().into_iter().collect::<Vec<_>>(),
+        derived()
+    );
+    assert_eq!(
+        compiled::converted().into_iter().collect::<Vec<_>>(),
+        converted()
+    );
+}
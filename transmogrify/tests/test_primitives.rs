@@ -0,0 +1,61 @@
+//! Integers of every width, including the pointer-sized ones, must round-trip
+//! at their extremes; so must chars that need escaping, and the unit value.
+//! Floats have their own tests in `test_float.rs`.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_fn_and_const};
+use transmogrify::Context;
+
+compiled!("primitive_values");
+
+fn isizes() -> [isize; 3] {
+    [isize::MIN, -3, isize::MAX]
+}
+
+fn usizes() -> [usize; 2] {
+    [0, usize::MAX]
+}
+
+fn i128s() -> [i128; 3] {
+    [i128::MIN, -1, i128::MAX]
+}
+
+fn u128s() -> [u128; 2] {
+    [0, u128::MAX]
+}
+
+fn chars() -> [char; 5] {
+    ['a', '\'', '\\', '\0', char::MAX]
+}
+
+#[test]
+fn test_primitive_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn_and_const("isizes", syn::parse_quote! { isize }, &isizes(), &cx),
+        emit_fn_and_const("usizes", syn::parse_quote! { usize }, &usizes(), &cx),
+        emit_fn_and_const("i128s", syn::parse_quote! { i128 }, &i128s(), &cx),
+        emit_fn_and_const("u128s", syn::parse_quote! { u128 }, &u128s(), &cx),
+        emit_fn_and_const("chars", syn::parse_quote! { char }, &chars(), &cx),
+        emit_fn_and_const("units", syn::parse_quote! { () }, &[()], &cx),
+    ];
+    assert_output("primitive_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_primitive_roundtrip() {
+    assert_eq!(compiled::ISIZES, isizes());
+    assert_eq!(compiled::isizes(), isizes());
+    assert_eq!(compiled::USIZES, usizes());
+    assert_eq!(compiled::usizes(), usizes());
+    assert_eq!(compiled::I128S, i128s());
+    assert_eq!(compiled::i128s(), i128s());
+    assert_eq!(compiled::U128S, u128s());
+    assert_eq!(compiled::u128s(), u128s());
+    assert_eq!(compiled::CHARS, chars());
+    assert_eq!(compiled::chars(), chars());
+    assert_eq!(compiled::UNITS, [()]);
+    assert_eq!(compiled::units(), [()]);
+}
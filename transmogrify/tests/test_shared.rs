@@ -0,0 +1,243 @@
+//! Smart pointers, cells, and locks must round-trip. With
+//! `transmogrify_shared`, pointers that were shared in the original value must
+//! still be shared in the value the output builds, and no others.
+
+#[macro_use]
+mod common;
+
+use std::{
+    cell::{Cell, OnceCell, RefCell},
+    collections::HashMap,
+    marker::PhantomData,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use common::{assert_output, emit_fn};
+use transmogrify::{transmogrify_shared, Context, Transmogrify};
+
+compiled!("shared_values");
+
+pub type Tree = Vec<Rc<Vec<Rc<String>>>>;
+pub type Pairs = HashMap<u8, (Rc<String>, Rc<String>)>;
+
+fn rcs() -> [Rc<u8>; 1] {
+    [Rc::new(1)]
+}
+
+fn arcs() -> [Arc<String>; 1] {
+    [Arc::new("s".to_string())]
+}
+
+fn cells() -> [Cell<u8>; 1] {
+    [Cell::new(1)]
+}
+
+fn ref_cells() -> [RefCell<Vec<u8>>; 1] {
+    [RefCell::new(vec![1, 2])]
+}
+
+fn once_cells() -> [OnceCell<u8>; 2] {
+    [OnceCell::from(1), OnceCell::new()]
+}
+
+fn mutexes() -> [Mutex<u8>; 1] {
+    [Mutex::new(1)]
+}
+
+fn rw_locks() -> [RwLock<u8>; 1] {
+    [RwLock::new(1)]
+}
+
+fn phantoms() -> [PhantomData<str>; 1] {
+    [PhantomData]
+}
+
+/// Two references to one pair, and one to a separate node holding the same
+/// leaf as the pair.
+fn tree() -> Tree {
+    let leaf = Rc::new("leaf".to_string());
+    let pair = Rc::new(vec![leaf.clone(), leaf.clone()]);
+    vec![pair.clone(), pair, Rc::new(vec![leaf])]
+}
+
+/// Pointers shared between entries of a map, which is emitted in an order
+/// other than the one it iterates in.
+fn pairs() -> Pairs {
+    let a = Rc::new("a".to_string());
+    let b = Rc::new("b".to_string());
+    (0..8)
+        .map(|i| match i % 3 {
+            0 => (i, (a.clone(), b.clone())),
+            1 => (i, (b.clone(), a.clone())),
+            _ => (i, (Rc::new(i.to_string()), b.clone())),
+        })
+        .collect()
+}
+
+#[test]
+fn test_shared_output() {
+    let cx = Context::default();
+    let tree = transmogrify_shared(&tree(), &cx);
+    let unshared = transmogrify_shared(&Arc::new(1u8), &cx);
+    let pairs = transmogrify_shared(&pairs(), &cx);
+    let items = [
+        emit_fn("rcs", syn::parse_quote! { ::std::rc::Rc<u8> }, &rcs(), &cx),
+        emit_fn(
+            "arcs",
+            syn::parse_quote! { ::std::sync::Arc<::std::string::String> },
+            &arcs(),
+            &cx,
+        ),
+        emit_fn(
+            "cells",
+            syn::parse_quote! { ::std::cell::Cell<u8> },
+            &cells(),
+            &cx,
+        ),
+        emit_fn(
+            "ref_cells",
+            syn::parse_quote! { ::std::cell::RefCell<::std::vec::Vec<u8>> },
+            &ref_cells(),
+            &cx,
+        ),
+        emit_fn(
+            "once_cells",
+            syn::parse_quote! { ::std::cell::OnceCell<u8> },
+            &once_cells(),
+            &cx,
+        ),
+        emit_fn(
+            "mutexes",
+            syn::parse_quote! { ::std::sync::Mutex<u8> },
+            &mutexes(),
+            &cx,
+        ),
+        emit_fn(
+            "rw_locks",
+            syn::parse_quote! { ::std::sync::RwLock<u8> },
+            &rw_locks(),
+            &cx,
+        ),
+        emit_fn(
+            "phantoms",
+            syn::parse_quote! { ::std::marker::PhantomData<str> },
+            &phantoms(),
+            &cx,
+        ),
+        quote::quote! {
+            pub fn tree() -> crate::Tree {
+                #tree
+            }
+            pub fn unshared() -> ::std::sync::Arc<u8> {
+                #unshared
+            }
+            pub fn pairs() -> crate::Pairs {
+                #pairs
+            }
+        },
+    ];
+    assert_output("shared_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_shared_roundtrip() {
+    assert_eq!(compiled::rcs(), rcs());
+    assert_eq!(compiled::arcs(), arcs());
+    assert_eq!(compiled::cells(), cells());
+    assert_eq!(compiled::ref_cells(), ref_cells());
+    assert_eq!(compiled::once_cells(), once_cells());
+    assert_eq!(
+        compiled::mutexes().map(|lock| lock.into_inner().unwrap()),
+        mutexes().map(|lock| lock.into_inner().unwrap()),
+    );
+    assert_eq!(
+        compiled::rw_locks().map(|lock| lock.into_inner().unwrap()),
+        rw_locks().map(|lock| lock.into_inner().unwrap()),
+    );
+    assert_eq!(compiled::phantoms(), phantoms());
+    assert_eq!(*compiled::unshared(), 1);
+}
+
+#[test]
+fn test_shared_pointers() {
+    let tree = compiled::tree();
+    assert_eq!(tree, self::tree());
+    assert!(Rc::ptr_eq(&tree[0], &tree[1]));
+    assert!(!Rc::ptr_eq(&tree[0], &tree[2]));
+    assert!(Rc::ptr_eq(&tree[0][0], &tree[0][1]));
+    assert!(Rc::ptr_eq(&tree[0][0], &tree[2][0]));
+
+    let pairs = compiled::pairs();
+    assert_eq!(pairs, self::pairs());
+    let (a, b) = &pairs[&0];
+    assert!(!Rc::ptr_eq(a, b));
+    for (i, (first, second)) in &pairs {
+        match i % 3 {
+            0 => assert!(Rc::ptr_eq(first, a) && Rc::ptr_eq(second, b)),
+            1 => assert!(Rc::ptr_eq(first, b) && Rc::ptr_eq(second, a)),
+            _ => assert!(Rc::ptr_eq(second, b)),
+        }
+    }
+}
+
+#[test]
+fn test_shared_order() {
+    // Each map has its own random state, so these iterate in different orders
+    // but must produce the same output.
+    let cx = Context::default();
+    let expected = transmogrify_shared(&pairs(), &cx).to_string();
+    for _ in 0..20 {
+        assert_eq!(transmogrify_shared(&pairs(), &cx).to_string(), expected);
+    }
+}
+
+#[test]
+fn test_shared_borrowed_ref_cell() {
+    let cell = RefCell::new(1u8);
+    let _guard = cell.borrow_mut();
+    // The rest of the message is `BorrowError`'s, which varies by toolchain.
+    let output = cell.transmogrify().to_string();
+    assert!(
+        output.starts_with(":: std :: compile_error ! (\"RefCell could not be transmogrified: "),
+        "{}",
+        output
+    );
+    assert!(output.contains("already mutably borrowed"), "{}", output);
+}
+
+#[test]
+fn test_shared_held_locks() {
+    let mutex = Mutex::new(1u8);
+    let guard = mutex.lock().unwrap();
+    let expected = quote::quote! {
+        ::std::compile_error!(
+            "Mutex could not be transmogrified: try_lock failed because the operation would block"
+        )
+    };
+    assert_eq!(mutex.transmogrify().to_string(), expected.to_string());
+    drop(guard);
+
+    let rw_lock = RwLock::new(1u8);
+    let _guard = rw_lock.write().unwrap();
+    let expected = quote::quote! {
+        ::std::compile_error!(
+            "RwLock could not be transmogrified: try_lock failed because the operation would block"
+        )
+    };
+    assert_eq!(rw_lock.transmogrify().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_shared_poisoned_lock() {
+    let mutex = Mutex::new(1u8);
+    let _ = std::panic::catch_unwind(|| {
+        let _guard = mutex.lock().unwrap();
+        panic!("poison the lock");
+    });
+    assert!(mutex.is_poisoned());
+    assert_eq!(
+        mutex.transmogrify().to_string(),
+        Mutex::new(1u8).transmogrify().to_string()
+    );
+}
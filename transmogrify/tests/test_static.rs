@@ -0,0 +1,40 @@
+//! `emit_static` and `emit_static_fn` wrap a value in a lazily initialized
+//! static. The items must be usable as emitted, with a visibility prepended,
+//! and must produce the value on first use.
+
+#[macro_use]
+mod common;
+
+use std::collections::BTreeMap;
+
+use common::assert_output;
+use transmogrify::{emit_static, emit_static_fn};
+
+compiled!("static_values");
+
+fn map() -> BTreeMap<u8, String> {
+    BTreeMap::from([(1, "a".to_string()), (2, "b".to_string())])
+}
+
+#[test]
+fn test_static_output() {
+    let ty = quote::quote! { ::std::collections::BTreeMap<u8, ::std::string::String> };
+    let name = quote::format_ident!("MAP");
+    let lazy = emit_static(&name, ty.clone(), &map());
+    let name = quote::format_ident!("map");
+    let accessor = emit_static_fn(&name, ty, &map());
+    assert_output(
+        "static_values",
+        quote::quote! {
+            pub #lazy
+            pub #accessor
+        },
+    );
+}
+
+#[test]
+fn test_static_roundtrip() {
+    assert_eq!(*compiled::MAP, map());
+    assert_eq!(*compiled::map(), map());
+    assert!(std::ptr::eq(compiled::map(), compiled::map()));
+}
@@ -0,0 +1,175 @@
+//! `Duration`, `SystemTime`, network addresses, and paths must round-trip.
+//! Times before the epoch and durations with sub-second parts are worth
+//! checking, as are non-UTF-8 paths, which only Unix can emit losslessly.
+
+#[macro_use]
+mod common;
+
+use std::{
+    ffi::OsString,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::{assert_output, emit_fn, emit_fn_and_const};
+use transmogrify::Context;
+
+compiled!("std_values");
+
+fn durations() -> [Duration; 3] {
+    [Duration::from_secs(3), Duration::new(1, 5), Duration::MAX]
+}
+
+fn system_times() -> [SystemTime; 3] {
+    [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::from_millis(1_500),
+        UNIX_EPOCH - Duration::from_secs(2),
+    ]
+}
+
+fn ipv4s() -> [Ipv4Addr; 2] {
+    [Ipv4Addr::LOCALHOST, Ipv4Addr::BROADCAST]
+}
+
+fn ipv6s() -> [Ipv6Addr; 1] {
+    [Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)]
+}
+
+fn ips() -> [IpAddr; 2] {
+    [
+        IpAddr::V4(Ipv4Addr::LOCALHOST),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+    ]
+}
+
+fn sockets() -> [SocketAddr; 2] {
+    [
+        SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 80)),
+        SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 1, 2)),
+    ]
+}
+
+fn os_strings() -> [OsString; 2] {
+    [OsString::from("s"), OsString::new()]
+}
+
+fn paths() -> [PathBuf; 2] {
+    [
+        PathBuf::from("/etc/hosts"),
+        PathBuf::from("relative/\"path\""),
+    ]
+}
+
+#[test]
+fn test_std_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn_and_const(
+            "durations",
+            syn::parse_quote! { ::std::time::Duration },
+            &durations(),
+            &cx,
+        ),
+        emit_fn(
+            "system_times",
+            syn::parse_quote! { ::std::time::SystemTime },
+            &system_times(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "ipv4s",
+            syn::parse_quote! { ::std::net::Ipv4Addr },
+            &ipv4s(),
+            &cx,
+        ),
+        emit_fn_and_const(
+            "ipv6s",
+            syn::parse_quote! { ::std::net::Ipv6Addr },
+            &ipv6s(),
+            &cx,
+        ),
+        emit_fn_and_const("ips", syn::parse_quote! { ::std::net::IpAddr }, &ips(), &cx),
+        emit_fn_and_const(
+            "sockets",
+            syn::parse_quote! { ::std::net::SocketAddr },
+            &sockets(),
+            &cx,
+        ),
+        emit_fn(
+            "os_strings",
+            syn::parse_quote! { ::std::ffi::OsString },
+            &os_strings(),
+            &cx,
+        ),
+        emit_fn(
+            "paths",
+            syn::parse_quote! { ::std::path::PathBuf },
+            &paths(),
+            &cx,
+        ),
+    ];
+    assert_output("std_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_std_roundtrip() {
+    assert_eq!(compiled::DURATIONS, durations());
+    assert_eq!(compiled::durations(), durations());
+    assert_eq!(compiled::system_times(), system_times());
+    assert_eq!(compiled::IPV4S, ipv4s());
+    assert_eq!(compiled::ipv4s(), ipv4s());
+    assert_eq!(compiled::IPV6S, ipv6s());
+    assert_eq!(compiled::ipv6s(), ipv6s());
+    assert_eq!(compiled::IPS, ips());
+    assert_eq!(compiled::ips(), ips());
+    assert_eq!(compiled::SOCKETS, sockets());
+    assert_eq!(compiled::sockets(), sockets());
+    assert_eq!(compiled::os_strings(), os_strings());
+    assert_eq!(compiled::paths(), paths());
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt, path::PathBuf};
+
+    use crate::common::{assert_output, emit_fn};
+    use transmogrify::Context;
+
+    compiled!("std_unix_values");
+
+    fn os_strings() -> [OsString; 1] {
+        [OsString::from_vec(vec![b'a', 0xff])]
+    }
+
+    fn paths() -> [PathBuf; 1] {
+        os_strings().map(PathBuf::from)
+    }
+
+    #[test]
+    fn test_unix_output() {
+        let cx = Context::default();
+        let items = [
+            emit_fn(
+                "os_strings",
+                syn::parse_quote! { ::std::ffi::OsString },
+                &os_strings(),
+                &cx,
+            ),
+            emit_fn(
+                "paths",
+                syn::parse_quote! { ::std::path::PathBuf },
+                &paths(),
+                &cx,
+            ),
+        ];
+        assert_output("std_unix_values", quote::quote! { #( #items )* });
+    }
+
+    #[test]
+    fn test_unix_roundtrip() {
+        assert_eq!(compiled::os_strings(), os_strings());
+        assert_eq!(compiled::paths(), paths());
+    }
+}
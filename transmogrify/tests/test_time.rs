@@ -0,0 +1,148 @@
+//! `time` values are emitted with the crate's constructors or, optionally, its
+//! macros; both forms, runtime and const, must reproduce the value exactly.
+//! The output of each mode is compiled in its own module.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_fn_and_const};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use transmogrify::Context;
+
+compiled!("time_values");
+
+fn dates() -> Vec<Date> {
+    vec![
+        Date::MIN,
+        Date::MAX,
+        Date::from_calendar_date(1970, Month::January, 1).unwrap(),
+        Date::from_calendar_date(2000, Month::February, 29).unwrap(),
+    ]
+}
+
+fn times() -> Vec<Time> {
+    vec![
+        Time::MIDNIGHT,
+        Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        Time::from_hms_milli(12, 34, 56, 789).unwrap(),
+    ]
+}
+
+fn offsets() -> Vec<UtcOffset> {
+    vec![
+        UtcOffset::from_hms(5, 45, 0).unwrap(),
+        UtcOffset::from_hms(-23, -59, -59).unwrap(),
+    ]
+}
+
+fn date_times() -> Vec<PrimitiveDateTime> {
+    dates()
+        .into_iter()
+        .zip(times().into_iter().cycle())
+        .map(|(date, time)| PrimitiveDateTime::new(date, time))
+        .collect()
+}
+
+/// Compared field by field since `OffsetDateTime` equality considers only the
+/// instant, not the offset.
+fn offset_date_times() -> Vec<OffsetDateTime> {
+    date_times()
+        .into_iter()
+        .skip(2)
+        .zip(offsets())
+        .map(|(local, offset)| local.assume_offset(offset))
+        .collect()
+}
+
+fn durations() -> Vec<Duration> {
+    vec![
+        Duration::MIN,
+        Duration::MAX,
+        Duration::new(-2, -500_000_000),
+    ]
+}
+
+fn emit_all(cx: &Context) -> proc_macro2::TokenStream {
+    let items = [
+        emit_fn_and_const("dates", syn::parse_quote! { ::time::Date }, &dates(), cx),
+        emit_fn_and_const("times", syn::parse_quote! { ::time::Time }, &times(), cx),
+        emit_fn_and_const(
+            "offsets",
+            syn::parse_quote! { ::time::UtcOffset },
+            &offsets(),
+            cx,
+        ),
+        emit_fn_and_const(
+            "date_times",
+            syn::parse_quote! { ::time::PrimitiveDateTime },
+            &date_times(),
+            cx,
+        ),
+        emit_fn_and_const(
+            "offset_date_times",
+            syn::parse_quote! { ::time::OffsetDateTime },
+            &offset_date_times(),
+            cx,
+        ),
+        emit_fn_and_const(
+            "durations",
+            syn::parse_quote! { ::time::Duration },
+            &durations(),
+            cx,
+        ),
+    ];
+    quote::quote! { #( #items )* }
+}
+
+#[test]
+fn test_time_output() {
+    let constructors = emit_all(&Context::default());
+    let macros = emit_all(&Context::default().with_time_macros(true));
+    assert_output(
+        "time_values",
+        quote::quote! {
+            pub mod constructors {
+                #constructors
+            }
+            pub mod macros {
+                #macros
+            }
+        },
+    );
+}
+
+#[test]
+fn test_time_roundtrip() {
+    let parts = |values: &[OffsetDateTime]| {
+        values
+            .iter()
+            .map(|value| (value.date(), value.time(), value.offset()))
+            .collect::<Vec<_>>()
+    };
+
+    macro_rules! check {
+        ($module:ident) => {
+            assert_eq!(compiled::$module::DATES.to_vec(), dates());
+            assert_eq!(compiled::$module::dates().to_vec(), dates());
+            assert_eq!(compiled::$module::TIMES.to_vec(), times());
+            assert_eq!(compiled::$module::times().to_vec(), times());
+            assert_eq!(compiled::$module::OFFSETS.to_vec(), offsets());
+            assert_eq!(compiled::$module::offsets().to_vec(), offsets());
+            assert_eq!(compiled::$module::DATE_TIMES.to_vec(), date_times());
+            assert_eq!(compiled::$module::date_times().to_vec(), date_times());
+            assert_eq!(
+                parts(&compiled::$module::OFFSET_DATE_TIMES),
+                parts(&offset_date_times()),
+            );
+            assert_eq!(
+                parts(&compiled::$module::offset_date_times()),
+                parts(&offset_date_times()),
+            );
+            assert_eq!(compiled::$module::DURATIONS.to_vec(), durations());
+            assert_eq!(compiled::$module::durations().to_vec(), durations());
+        };
+    }
+
+    check!(constructors);
+    check!(macros);
+}
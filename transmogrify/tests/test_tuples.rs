@@ -0,0 +1,85 @@
+//! Tuples up to twelve elements and arrays of any length must round-trip.
+//! Byte arrays are emitted as byte strings, so bytes that need escaping are
+//! worth checking, as are arrays of arrays and empty arrays.
+
+#[macro_use]
+mod common;
+
+use common::{assert_output, emit_const, emit_fn, emit_fn_and_const};
+use transmogrify::Context;
+
+compiled!("tuple_values");
+
+pub type Twelve = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+fn singles() -> [(bool,); 2] {
+    [(true,), (false,)]
+}
+
+fn pairs() -> [(u32, String); 1] {
+    [(1, "s".to_string())]
+}
+
+fn twelves() -> [Twelve; 1] {
+    [(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)]
+}
+
+fn words() -> [[u16; 2]; 1] {
+    [[1, u16::MAX]]
+}
+
+fn bytes() -> [[u8; 4]; 2] {
+    [[0, b'"', b'\\', 0xff], *b"abcd"]
+}
+
+fn empty() -> [[u8; 0]; 1] {
+    [[]]
+}
+
+fn nested() -> [[[u8; 1]; 2]; 1] {
+    [[[1], [2]]]
+}
+
+#[test]
+fn test_tuple_output() {
+    let cx = Context::default();
+    let items = [
+        emit_fn_and_const("singles", syn::parse_quote! { (bool,) }, &singles(), &cx),
+        emit_fn(
+            "pairs",
+            syn::parse_quote! { (u32, ::std::string::String) },
+            &pairs(),
+            &cx,
+        ),
+        emit_const("pairs", syn::parse_quote! { (u32, &str) }, &pairs(), &cx),
+        emit_fn_and_const(
+            "twelves",
+            syn::parse_quote! { crate::Twelve },
+            &twelves(),
+            &cx,
+        ),
+        emit_fn_and_const("words", syn::parse_quote! { [u16; 2] }, &words(), &cx),
+        emit_fn_and_const("bytes", syn::parse_quote! { [u8; 4] }, &bytes(), &cx),
+        emit_fn_and_const("empty", syn::parse_quote! { [u8; 0] }, &empty(), &cx),
+        emit_fn_and_const("nested", syn::parse_quote! { [[u8; 1]; 2] }, &nested(), &cx),
+    ];
+    assert_output("tuple_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_tuple_roundtrip() {
+    assert_eq!(compiled::SINGLES, singles());
+    assert_eq!(compiled::singles(), singles());
+    assert_eq!(compiled::PAIRS, [(1, "s")]);
+    assert_eq!(compiled::pairs(), pairs());
+    assert_eq!(compiled::TWELVES, twelves());
+    assert_eq!(compiled::twelves(), twelves());
+    assert_eq!(compiled::WORDS, words());
+    assert_eq!(compiled::words(), words());
+    assert_eq!(compiled::BYTES, bytes());
+    assert_eq!(compiled::bytes(), bytes());
+    assert_eq!(compiled::EMPTY, empty());
+    assert_eq!(compiled::empty(), empty());
+    assert_eq!(compiled::NESTED, nested());
+    assert_eq!(compiled::nested(), nested());
+}
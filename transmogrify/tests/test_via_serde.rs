@@ -0,0 +1,77 @@
+//! Values emitted via serde are embedded as JSON and deserialized where the
+//! output is used, whether through `via_serde` or the derive's `via = "json"`.
+//! JSON that needs escaping must survive the trip through a raw string.
+
+#[macro_use]
+mod common;
+
+use std::collections::{BTreeMap, HashMap};
+
+use common::{assert_output, emit_fn};
+use transmogrify::{via_serde, Context, Transmogrify};
+
+compiled!("via_serde_values");
+
+#[derive(Debug, PartialEq, Transmogrify, serde::Serialize, serde::Deserialize)]
+#[transmogrify(via = "json")]
+pub struct TestVia {
+    values: BTreeMap<u32, String>,
+}
+
+fn tables() -> Vec<Vec<(u32, String)>> {
+    vec![
+        vec![(1, "one".to_string()), (2, "two".to_string())],
+        vec![(0, "\"#quoted#\"".to_string())],
+        Vec::new(),
+    ]
+}
+
+fn derived() -> Vec<TestVia> {
+    vec![TestVia {
+        values: BTreeMap::from([(1, "a".to_string()), (2, "b".to_string())]),
+    }]
+}
+
+#[test]
+fn test_via_serde_output() {
+    let cx = Context::default();
+    let tables = tables();
+    let tables = tables.iter().map(via_serde).collect::<Vec<_>>();
+    let items = [
+        emit_fn(
+            "tables",
+            syn::parse_quote! { ::std::vec::Vec<(u32, ::std::string::String)> },
+            &tables,
+            &cx,
+        ),
+        emit_fn(
+            "derived",
+            syn::parse_quote! { crate::TestVia },
+            &derived(),
+            &cx,
+        ),
+    ];
+    assert_output("via_serde_values", quote::quote! { #( #items )* });
+}
+
+#[test]
+fn test_via_serde_roundtrip() {
+    assert_eq!(compiled::tables().to_vec(), tables());
+    assert_eq!(
+        compiled::derived().into_iter().collect::<Vec<_>>(),
+        derived()
+    );
+}
+
+#[test]
+fn test_via_serde_error() {
+    // JSON object keys must be strings.
+    let value = HashMap::from([((1u8, 2u8), 3u8)]);
+    let expected = quote::quote! {
+        ::std::compile_error!("value could not be serialized: key must be a string")
+    };
+    assert_eq!(
+        via_serde(&value).transmogrify().to_string(),
+        expected.to_string()
+    );
+}